@@ -0,0 +1,35 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use semver::{Version, VersionReq};
+use std::hint;
+
+fn bench(c: &mut Criterion) {
+    let req = VersionReq::parse(">=1.2.3, <1.8.0").unwrap();
+    let compiled = req.compile();
+    let version = Version::parse("1.7.11").unwrap();
+    let versions: Vec<Version> = (0..1000).map(|i| Version::new(1, i % 10, i / 10)).collect();
+
+    c.bench_function("VersionReq::matches", |b| {
+        b.iter(|| hint::black_box(&req).matches(hint::black_box(&version)));
+    });
+    c.bench_function("CompiledReq::matches", |b| {
+        b.iter(|| hint::black_box(&compiled).matches(hint::black_box(&version)));
+    });
+    c.bench_function("VersionReq::matches x1000", |b| {
+        b.iter(|| {
+            let req = hint::black_box(&req);
+            hint::black_box(&versions)
+                .iter()
+                .map(|version| req.matches(version))
+                .collect::<Vec<bool>>()
+        });
+    });
+    c.bench_function("CompiledReq::matches_many x1000", |b| {
+        b.iter(|| hint::black_box(&compiled).matches_many(hint::black_box(&versions)));
+    });
+    c.bench_function("VersionReq::compile", |b| {
+        b.iter(|| hint::black_box(&req).compile());
+    });
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
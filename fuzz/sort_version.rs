@@ -9,6 +9,16 @@ fuzz_target!(|bytes: &[u8]| {
         if a < b && b < c && c < a {
             panic!("{0} < {1} < {2} < {0}", a, b, c);
         }
+        for (x, y) in [(&a, &b), (&b, &c), (&c, &a)] {
+            let ord = x.to_sortable_bytes().cmp(&y.to_sortable_bytes());
+            assert_eq!(ord, x.cmp(y), "{} {}", x, y);
+            let ord = x.to_precedence_bytes().cmp(&y.to_precedence_bytes());
+            assert_eq!(ord, x.cmp_precedence(y), "{} {}", x, y);
+        }
+        for v in [&a, &b, &c] {
+            let decoded = Version::from_sortable_bytes(&v.to_sortable_bytes()).unwrap();
+            assert_eq!(decoded, *v);
+        }
     }
 });
 
@@ -0,0 +1,163 @@
+//! [`Arbitrary`] impls for generating versions and requirements from fuzzer
+//! input.
+//!
+//! With the `arbitrary` feature enabled, [`Version`], [`VersionReq`],
+//! [`Comparator`], [`Op`], [`Prerelease`] and [`BuildMetadata`] implement
+//! `Arbitrary`, producing only valid values. Numbers are biased toward small
+//! values so that generated versions and requirements frequently interact with
+//! one another.
+//!
+//! In addition, [`matching`] and [`not_matching`] generate versions on either
+//! side of a particular requirement.
+//!
+//! # Example
+//!
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use semver::{Version, VersionReq};
+//!
+//! let data = [7, 2, 9, 1, 0, 255, 13, 42, 8, 3, 6, 5, 4, 1, 77, 2, 9, 0, 0, 1];
+//! let mut u = Unstructured::new(&data);
+//!
+//! let req = VersionReq::arbitrary(&mut u).unwrap();
+//! let version = semver::arbitrary::not_matching(&mut u, &req).unwrap();
+//! assert!(!req.matches(&version));
+//! ```
+
+use crate::generate::{self, Identifier, Perturbation};
+use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+use alloc::string::String;
+use alloc::vec::Vec;
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+/// Generate a version that matches the given requirement.
+///
+/// Fails with [`arbitrary::Error::IncorrectFormat`] if no version matches the
+/// requirement, as in `>1, <1`.
+pub fn matching(u: &mut Unstructured, req: &VersionReq) -> Result<Version> {
+    side(u, req, true)
+}
+
+/// Generate a version that does not match the given requirement.
+///
+/// Fails with [`arbitrary::Error::IncorrectFormat`] if every version matches
+/// the requirement, as in `*`.
+pub fn not_matching(u: &mut Unstructured, req: &VersionReq) -> Result<Version> {
+    side(u, req, false)
+}
+
+fn side(u: &mut Unstructured, req: &VersionReq, want_match: bool) -> Result<Version> {
+    let seeds = generate::seeds(req);
+    let seeds = if want_match {
+        seeds.matching
+    } else {
+        seeds.not_matching
+    };
+    if seeds.is_empty() {
+        return Err(arbitrary::Error::IncorrectFormat);
+    }
+    let index = u.choose_index(seeds.len())?;
+    let perturbation = Perturbation {
+        kind: u.arbitrary()?,
+        amount: number(u)?,
+        pre: u.arbitrary()?,
+        build: u.arbitrary()?,
+    };
+    Ok(generate::pick(
+        req,
+        &seeds,
+        want_match,
+        index,
+        &perturbation,
+    ))
+}
+
+fn number(u: &mut Unstructured) -> Result<u64> {
+    match u.int_in_range(0..=6u8)? {
+        0..=3 => u.int_in_range(0..=3),
+        4 | 5 => u.int_in_range(0..=99),
+        _ => u.arbitrary(),
+    }
+}
+
+fn identifiers(u: &mut Unstructured) -> Result<String> {
+    let len = u.int_in_range(1..=3)?;
+    let mut identifiers = Vec::with_capacity(len);
+    for _ in 0..len {
+        identifiers.push(if u.arbitrary()? {
+            Identifier::Numeric(number(u)?)
+        } else {
+            let len = u.int_in_range(1..=7)?;
+            Identifier::Alphanumeric((0..len).map(|_| u.arbitrary()).collect::<Result<_>>()?)
+        });
+    }
+    Ok(generate::dotted(identifiers))
+}
+
+impl<'a> Arbitrary<'a> for Version {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Version {
+            major: number(u)?,
+            minor: number(u)?,
+            patch: number(u)?,
+            pre: u.arbitrary()?,
+            build: u.arbitrary()?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Prerelease {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            Ok(generate::prerelease(&identifiers(u)?))
+        } else {
+            Ok(Prerelease::EMPTY)
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for BuildMetadata {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.ratio(2, 3)? {
+            Ok(BuildMetadata::EMPTY)
+        } else {
+            Ok(generate::build_metadata(&identifiers(u)?))
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for Op {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&generate::OPS).copied()
+    }
+}
+
+impl<'a> Arbitrary<'a> for Comparator {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let op = u.arbitrary()?;
+        let major = number(u)?;
+        let minor = if u.arbitrary()? {
+            Some(number(u)?)
+        } else {
+            None
+        };
+        let patch = if u.arbitrary()? {
+            Some(number(u)?)
+        } else {
+            None
+        };
+        let pre = u.arbitrary()?;
+        Ok(generate::comparator(op, major, minor, patch, pre))
+    }
+}
+
+impl<'a> Arbitrary<'a> for VersionReq {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let len = u.int_in_range(0..=3)?;
+        let mut comparators = Vec::with_capacity(len);
+        for _ in 0..len {
+            comparators.push(u.arbitrary()?);
+        }
+        Ok(VersionReq { comparators })
+    }
+}
@@ -0,0 +1,225 @@
+use crate::error::Position;
+use crate::impls::cmp_prerelease;
+use crate::parse::{self, Error};
+use crate::{eval, BuildMetadata, Comparator, Op, Prerelease, Version};
+use core::cmp::Ordering;
+
+/// **SemVer version** borrowing its pre-release and build metadata from the
+/// string it was parsed from.
+///
+/// Parsing a [`Version`] allocates for any pre-release or build metadata
+/// longer than 8 bytes. `VersionRef` never allocates, which makes it suited
+/// to scanning a large number of versions that only need to be compared or
+/// matched against a requirement, keeping the few that are needed by
+/// converting them with [`VersionRef::to_version`].
+///
+/// The ordering, equality, and hashing of `VersionRef` are the same as for
+/// the corresponding `Version`.
+///
+/// # Example
+///
+/// ```
+/// use semver::{VersionRef, VersionReq};
+///
+/// let index = "1.0.0\n1.1.0-alpha.20240101\n1.1.0\n2.0.0-rc.1";
+/// let req = VersionReq::parse("^1").unwrap();
+///
+/// let newest = index
+///     .lines()
+///     .map(|line| VersionRef::parse(line).unwrap())
+///     .filter(|version| req.matches_ref(version))
+///     .max()
+///     .unwrap();
+/// assert_eq!(newest.to_version().to_string(), "1.1.0");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+pub struct VersionRef<'a> {
+    pub(crate) major: u64,
+    pub(crate) minor: u64,
+    pub(crate) patch: u64,
+    pub(crate) pre: &'a str,
+    pub(crate) build: &'a str,
+}
+
+/// **A pair of comparison operator and partial version** borrowing its
+/// pre-release from the string it was parsed from.
+///
+/// This is the borrowed counterpart of [`Comparator`], in the same way that
+/// [`VersionRef`] is of [`Version`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ComparatorRef<'a> {
+    pub(crate) op: Op,
+    pub(crate) major: u64,
+    pub(crate) minor: Option<u64>,
+    pub(crate) patch: Option<u64>,
+    pub(crate) pre: &'a str,
+}
+
+impl<'a> VersionRef<'a> {
+    /// Parse a version without copying any part of the input.
+    ///
+    /// Accepts exactly the same syntax as [`Version::parse`].
+    pub fn parse(text: &'a str) -> Result<Self, Error> {
+        parse::version(text)
+    }
+
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+
+    pub fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    pub fn patch(&self) -> u64 {
+        self.patch
+    }
+
+    /// The pre-release, or the empty string if none.
+    pub fn pre(&self) -> &'a str {
+        self.pre
+    }
+
+    /// The build metadata, or the empty string if none.
+    pub fn build(&self) -> &'a str {
+        self.build
+    }
+
+    /// Copy into an owned `Version`.
+    pub fn to_version(&self) -> Version {
+        Version {
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            pre: Prerelease {
+                identifier: parse::new_identifier(self.pre),
+            },
+            build: BuildMetadata {
+                identifier: parse::new_identifier(self.build),
+            },
+        }
+    }
+
+    // Fails instead of allocating if pre or build is too long to store inline.
+    pub(crate) fn to_inline_version(self) -> Result<Version, Error> {
+        Ok(Version {
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            pre: Prerelease {
+                identifier: parse::inline_identifier(self.pre, Position::Pre)?,
+            },
+            build: BuildMetadata {
+                identifier: parse::inline_identifier(self.build, Position::Build)?,
+            },
+        })
+    }
+
+    /// Compare the major, minor, patch, and pre-release value of two versions,
+    /// disregarding build metadata. See [`Version::cmp_precedence`].
+    pub fn cmp_precedence(&self, other: &Self) -> Ordering {
+        Ord::cmp(
+            &(self.major, self.minor, self.patch),
+            &(other.major, other.minor, other.patch),
+        )
+        .then_with(|| cmp_prerelease(self.pre, other.pre))
+    }
+}
+
+impl<'a> ComparatorRef<'a> {
+    /// Parse a comparator without copying any part of the input.
+    ///
+    /// Accepts exactly the same syntax as [`Comparator::parse`].
+    pub fn parse(text: &'a str) -> Result<Self, Error> {
+        parse::comparator_ref(text)
+    }
+
+    pub fn op(&self) -> Op {
+        self.op
+    }
+
+    pub fn major(&self) -> u64 {
+        self.major
+    }
+
+    pub fn minor(&self) -> Option<u64> {
+        self.minor
+    }
+
+    pub fn patch(&self) -> Option<u64> {
+        self.patch
+    }
+
+    /// The pre-release, or the empty string if none.
+    pub fn pre(&self) -> &'a str {
+        self.pre
+    }
+
+    /// Evaluate whether the given version satisfies this comparator. See
+    /// [`Comparator::matches`].
+    pub fn matches(&self, version: &VersionRef) -> bool {
+        eval::matches_comparator(self, version)
+    }
+
+    /// Copy into an owned `Comparator`.
+    pub fn to_comparator(&self) -> Comparator {
+        Comparator {
+            op: self.op,
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            pre: Prerelease {
+                identifier: parse::new_identifier(self.pre),
+            },
+        }
+    }
+
+    // Fails instead of allocating if pre is too long to store inline.
+    pub(crate) fn to_inline_comparator(self) -> Result<Comparator, Error> {
+        Ok(Comparator {
+            op: self.op,
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            pre: Prerelease {
+                identifier: parse::inline_identifier(self.pre, Position::Pre)?,
+            },
+        })
+    }
+}
+
+impl<'a> From<&'a Version> for VersionRef<'a> {
+    fn from(version: &'a Version) -> Self {
+        VersionRef {
+            major: version.major,
+            minor: version.minor,
+            patch: version.patch,
+            pre: version.pre.as_str(),
+            build: version.build.as_str(),
+        }
+    }
+}
+
+impl<'a> From<&'a Comparator> for ComparatorRef<'a> {
+    fn from(comparator: &'a Comparator) -> Self {
+        ComparatorRef {
+            op: comparator.op,
+            major: comparator.major,
+            minor: comparator.minor,
+            patch: comparator.patch,
+            pre: comparator.pre.as_str(),
+        }
+    }
+}
+
+impl From<VersionRef<'_>> for Version {
+    fn from(version: VersionRef) -> Self {
+        version.to_version()
+    }
+}
+
+impl From<ComparatorRef<'_>> for Comparator {
+    fn from(comparator: ComparatorRef) -> Self {
+        comparator.to_comparator()
+    }
+}
@@ -0,0 +1,94 @@
+use crate::interval::{self, Interval};
+use crate::{Version, VersionReq};
+use alloc::vec::Vec;
+use core::ops::Bound;
+
+/// Precomputed form of a [`VersionReq`] for evaluating it against many
+/// versions.
+///
+/// Produced by [`VersionReq::compile`]. Where [`VersionReq::matches`] walks
+/// through the rules of every comparator for each version, a `CompiledReq`
+/// normalizes the requirement once into a single lower and upper bound plus the
+/// set of major.minor.patch whose pre-releases are allowed to match. Matching a
+/// version that is not a pre-release then takes only a couple of comparisons of
+/// version numbers.
+///
+/// A `CompiledReq` always agrees with the `VersionReq` it was compiled from.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Version, VersionReq};
+///
+/// let req = VersionReq::parse(">=1.2.3, <1.8.0").unwrap();
+/// let compiled = req.compile();
+///
+/// let versions = [
+///     Version::parse("1.2.0").unwrap(),
+///     Version::parse("1.3.0").unwrap(),
+///     Version::parse("1.3.0-rc.1").unwrap(),
+/// ];
+/// assert!(compiled.matches(&versions[1]));
+/// assert_eq!(compiled.matches_many(&versions), [false, true, false]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CompiledReq {
+    pub(crate) lower: Bound<Version>,
+    pub(crate) upper: Bound<Version>,
+    // Sorted major.minor.patch of the comparators that have a pre-release,
+    // except those whose pre-releases are excluded by another comparator
+    // anyway, such as 1.2.3 in `>=1.2.3-alpha, ~1.2`.
+    pre_allowed: Vec<[u64; 3]>,
+}
+
+pub(crate) fn compile(req: &VersionReq) -> CompiledReq {
+    let intervals: Vec<Interval> = req.comparators.iter().map(Interval::of).collect();
+
+    let mut pre_allowed: Vec<[u64; 3]> = req
+        .comparators
+        .iter()
+        .filter(|cmp| !cmp.pre.is_empty())
+        .filter_map(|cmp| Some([cmp.major, cmp.minor?, cmp.patch?]))
+        .filter(|&[major, minor, patch]| {
+            let pre = interval::floor(major, minor, patch);
+            !intervals.iter().any(|interval| interval.excludes_pre(&pre))
+        })
+        .collect();
+    pre_allowed.sort_unstable();
+    pre_allowed.dedup();
+
+    let mut bounds = Interval::unbounded();
+    for interval in intervals {
+        bounds.intersect(interval);
+    }
+
+    CompiledReq {
+        lower: bounds.lower,
+        upper: bounds.upper,
+        pre_allowed,
+    }
+}
+
+impl CompiledReq {
+    /// Evaluate whether the given `Version` satisfies the version requirement
+    /// that this was compiled from.
+    pub fn matches(&self, version: &Version) -> bool {
+        if !version.pre.is_empty()
+            && self
+                .pre_allowed
+                .binary_search(&[version.major, version.minor, version.patch])
+                .is_err()
+        {
+            return false;
+        }
+        interval::is_above(&self.lower, version) && interval::is_below(&self.upper, version)
+    }
+
+    /// Evaluate the version requirement against each of the given versions.
+    pub fn matches_many(&self, versions: &[Version]) -> Vec<bool> {
+        versions
+            .iter()
+            .map(|version| self.matches(version))
+            .collect()
+    }
+}
@@ -0,0 +1,124 @@
+use crate::{BuildMetadata, Comparator, Op, Version, VersionReq};
+use alloc::string::String;
+use core::fmt::{self, Write};
+
+pub(crate) fn describe_req(req: &VersionReq) -> String {
+    let mut string = String::new();
+    match req.comparators.as_slice() {
+        [] => string.push_str("any version that is not a pre-release"),
+        [cmp] => {
+            let _ = write_comparator(&mut string, cmp);
+        }
+        comparators => {
+            // A version must satisfy every comparator.
+            string.push_str("all of: ");
+            for (i, cmp) in comparators.iter().enumerate() {
+                if i > 0 {
+                    string.push_str("; ");
+                }
+                let _ = write_comparator(&mut string, cmp);
+            }
+        }
+    }
+    string
+}
+
+pub(crate) fn describe_comparator(cmp: &Comparator) -> String {
+    let mut string = String::new();
+    let _ = write_comparator(&mut string, cmp);
+    string
+}
+
+fn write_comparator(out: &mut String, cmp: &Comparator) -> fmt::Result {
+    let major = cmp.major;
+    let Some(minor) = cmp.minor else {
+        return match cmp.op {
+            Op::Exact | Op::Tilde | Op::Caret | Op::Wildcard => {
+                write!(out, "any {}.x version", major)
+            }
+            Op::Greater => match major.checked_add(1) {
+                Some(next) => write!(out, "any version at or above {}.0.0", next),
+                None => out.write_str("no version"),
+            },
+            Op::GreaterEq => write!(out, "any version at or above {}.0.0", major),
+            Op::Less => write!(out, "any version below {}.0.0", major),
+            Op::LessEq => match major.checked_add(1) {
+                Some(next) => write!(out, "any version below {}.0.0", next),
+                None => out.write_str("any version"),
+            },
+        };
+    };
+
+    let Some(patch) = cmp.patch else {
+        return match cmp.op {
+            Op::Exact | Op::Tilde | Op::Wildcard => {
+                write!(out, "any {}.{}.x version", major, minor)
+            }
+            Op::Caret if major == 0 => write!(out, "any 0.{}.x version", minor),
+            Op::Caret => write!(
+                out,
+                "any {}.x version at or above {}.{}.0 (caret allows minor and patch updates)",
+                major, major, minor,
+            ),
+            Op::Greater => match next_minor(major, minor) {
+                Some((major, minor)) => {
+                    write!(out, "any version at or above {}.{}.0", major, minor)
+                }
+                None => out.write_str("no version"),
+            },
+            Op::GreaterEq => write!(out, "any version at or above {}.{}.0", major, minor),
+            Op::Less => write!(out, "any version below {}.{}.0", major, minor),
+            Op::LessEq => match next_minor(major, minor) {
+                Some((major, minor)) => write!(out, "any version below {}.{}.0", major, minor),
+                None => out.write_str("any version"),
+            },
+        };
+    };
+
+    let version = Version {
+        major,
+        minor,
+        patch,
+        pre: cmp.pre.clone(),
+        build: BuildMetadata::EMPTY,
+    };
+    match cmp.op {
+        Op::Exact | Op::Wildcard => write!(out, "exactly {}", version),
+        Op::Greater => write!(out, "any version above {}", version),
+        Op::GreaterEq => write!(out, "any version at or above {}", version),
+        Op::Less => write!(out, "any version below {}", version),
+        Op::LessEq => write!(out, "any version at or below {}", version),
+        Op::Tilde => write!(
+            out,
+            "any {}.{}.x version at or above {} (tilde only allows patch updates)",
+            major, minor, version,
+        ),
+        Op::Caret if major > 0 => write!(
+            out,
+            "any {}.x version at or above {} (caret allows minor and patch updates)",
+            major, version,
+        ),
+        Op::Caret if minor > 0 => write!(
+            out,
+            "any 0.{}.x version at or above {} (0.x caret only allows patch updates)",
+            minor, version,
+        ),
+        Op::Caret if cmp.pre.is_empty() => write!(
+            out,
+            "exactly {} (0.0.x caret does not allow any updates)",
+            version,
+        ),
+        Op::Caret => write!(
+            out,
+            "0.0.{} or a pre-release of it at or above {} (0.0.x caret does not allow any updates)",
+            patch, version,
+        ),
+    }
+}
+
+fn next_minor(major: u64, minor: u64) -> Option<(u64, u64)> {
+    match minor.checked_add(1) {
+        Some(minor) => Some((major, minor)),
+        None => Some((major.checked_add(1)?, 0)),
+    }
+}
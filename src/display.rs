@@ -1,7 +1,16 @@
-use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+use crate::{
+    BuildMetadata, Comparator, ComparatorRef, FixedVersionReq, Op, PackedVersion, PackedVersion128,
+    Prerelease, Version, VersionRef, VersionReq,
+};
 use core::fmt::{self, Alignment, Debug, Display, Write};
 
 impl Display for Version {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&VersionRef::from(self), formatter)
+    }
+}
+
+impl Display for VersionRef<'_> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         let do_display = |formatter: &mut fmt::Formatter| -> fmt::Result {
             write!(formatter, "{}.{}.{}", self.major, self.minor, self.patch)?;
@@ -32,20 +41,36 @@ impl Display for Version {
 
 impl Display for VersionReq {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        if self.comparators.is_empty() {
-            return formatter.write_str("*");
-        }
-        for (i, comparator) in self.comparators.iter().enumerate() {
-            if i > 0 {
-                formatter.write_str(", ")?;
-            }
-            write!(formatter, "{}", comparator)?;
+        display_comparators(&self.comparators, formatter)
+    }
+}
+
+impl<const N: usize> Display for FixedVersionReq<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        display_comparators(self.comparators(), formatter)
+    }
+}
+
+fn display_comparators(comparators: &[Comparator], formatter: &mut fmt::Formatter) -> fmt::Result {
+    if comparators.is_empty() {
+        return formatter.write_str("*");
+    }
+    for (i, comparator) in comparators.iter().enumerate() {
+        if i > 0 {
+            formatter.write_str(", ")?;
         }
-        Ok(())
+        write!(formatter, "{}", comparator)?;
     }
+    Ok(())
 }
 
 impl Display for Comparator {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        Display::fmt(&ComparatorRef::from(self), formatter)
+    }
+}
+
+impl Display for ComparatorRef<'_> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         let op = match self.op {
             Op::Exact => "=",
@@ -105,6 +130,71 @@ impl Debug for Version {
     }
 }
 
+impl Debug for VersionRef<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let mut debug = formatter.debug_struct("VersionRef");
+        debug
+            .field("major", &self.major)
+            .field("minor", &self.minor)
+            .field("patch", &self.patch);
+        if !self.pre.is_empty() {
+            debug.field("pre", &self.pre);
+        }
+        if !self.build.is_empty() {
+            debug.field("build", &self.build);
+        }
+        debug.finish()
+    }
+}
+
+impl<const N: usize> Debug for FixedVersionReq<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_struct("FixedVersionReq")
+            .field("comparators", &self.comparators())
+            .finish()
+    }
+}
+
+impl Debug for PackedVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        debug_packed(
+            formatter,
+            "PackedVersion",
+            [self.major(), self.minor(), self.patch()],
+            self.has_prerelease(),
+        )
+    }
+}
+
+impl Debug for PackedVersion128 {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        debug_packed(
+            formatter,
+            "PackedVersion128",
+            [self.major(), self.minor(), self.patch()],
+            self.has_prerelease(),
+        )
+    }
+}
+
+fn debug_packed(
+    formatter: &mut fmt::Formatter,
+    name: &str,
+    [major, minor, patch]: [u64; 3],
+    has_prerelease: bool,
+) -> fmt::Result {
+    let mut debug = formatter.debug_struct(name);
+    debug
+        .field("major", &major)
+        .field("minor", &minor)
+        .field("patch", &patch);
+    if has_prerelease {
+        debug.field("has_prerelease", &true);
+    }
+    debug.finish()
+}
+
 impl Debug for Prerelease {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         write!(formatter, "Prerelease(\"{}\")", self)
@@ -0,0 +1,199 @@
+use crate::parse;
+use crate::VersionRef;
+use core::cmp::Ordering;
+
+/// Compare two strings that may contain versions, such as file names or tags,
+/// ordering the embedded versions by [`Ord for Version`][crate::Version#impl-Ord-for-Version].
+///
+/// Both strings are split into single bytes and the versions found among them,
+/// and compared piece by piece: bytes by value, versions by `Ord for Version`,
+/// and a version right after the byte `9`, before any greater byte. So strings
+/// without a version are in plain byte order, and the result is a total order
+/// suitable for sorting.
+///
+/// A version is recognized wherever `major.minor.patch` begins that is not
+/// part of a longer number, as long as it has no leading zeros and fits in
+/// u64. Text after it may or may not belong to a pre-release or build
+/// metadata: in `tool-1.0.0-rc.1-linux.tar.gz`, the version could be `1.0.0`,
+/// `1.0.0-rc`, `1.0.0-rc.1`, `1.0.0-rc.1-linux` and so on. Each string is
+/// read on its own, regardless of what it is compared with, as the longest of
+/// those versions whose last identifier contains a digit, or whose pre-release
+/// or build metadata runs to the end of the string without a dot. Otherwise
+/// the version ends after its patch number.
+///
+/// So `tool-1.0.0-rc.1-linux.tar.gz` is a pre-release `1.0.0-rc.1` followed
+/// by `-linux.tar.gz`, and sorts before `tool-1.0.0-linux.tar.gz`, which is
+/// `1.0.0` followed by `-linux.tar.gz`. An identifier without digits is text,
+/// so `tool-1.0.0-linux.tar.gz` is compared with `tool-1.0.0.tar.gz` by the
+/// bytes after the version, and sorts first because `-` sorts before `.`.
+///
+/// # Example
+///
+/// ```
+/// let mut files = [
+///     "tool-1.10.0-linux.tar.gz",
+///     "tool-1.9.2-linux.tar.gz",
+///     "tool-1.10.0-rc.1-linux.tar.gz",
+///     "README.md",
+/// ];
+/// files.sort_by(|a, b| semver::cmp_embedded(a, b));
+/// assert_eq!(
+///     files,
+///     [
+///         "README.md",
+///         "tool-1.9.2-linux.tar.gz",
+///         "tool-1.10.0-rc.1-linux.tar.gz",
+///         "tool-1.10.0-linux.tar.gz",
+///     ],
+/// );
+/// ```
+pub fn cmp_embedded(lhs: &str, rhs: &str) -> Ordering {
+    Iterator::cmp(Pieces::new(lhs), Pieces::new(rhs))
+}
+
+// A string as a sequence of bytes and the versions embedded in it.
+struct Pieces<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+#[derive(Eq, PartialEq)]
+enum Piece<'a> {
+    Byte(u8),
+    Version(VersionRef<'a>),
+}
+
+impl<'a> Pieces<'a> {
+    fn new(text: &'a str) -> Self {
+        Pieces { text, pos: 0 }
+    }
+}
+
+impl<'a> Iterator for Pieces<'a> {
+    type Item = Piece<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        let i = self.pos;
+        let byte = *bytes.get(i)?;
+        if byte.is_ascii_digit() && (i == 0 || !bytes[i - 1].is_ascii_digit()) {
+            if let Some((version, len)) = reading(&self.text[i..]) {
+                self.pos += len;
+                return Some(Piece::Version(version));
+            }
+        }
+        self.pos += 1;
+        Some(Piece::Byte(byte))
+    }
+}
+
+impl PartialOrd for Piece<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Piece<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Piece::Byte(lhs), Piece::Byte(rhs)) => lhs.cmp(rhs),
+            (Piece::Version(lhs), Piece::Version(rhs)) => lhs.cmp(rhs),
+            // A version sits between '9' and the byte after it.
+            (Piece::Byte(byte), Piece::Version(_)) => {
+                if *byte <= b'9' {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+            (Piece::Version(_), Piece::Byte(byte)) => {
+                if *byte <= b'9' {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            }
+        }
+    }
+}
+
+// The version at the start of the text, as described on cmp_embedded, and its
+// length.
+fn reading(text: &str) -> Option<(VersionRef<'_>, usize)> {
+    let mut chosen = None;
+    for (version, after) in readings(text) {
+        let len = text.len() - after.len();
+        let version_text = &text[..len];
+        let last = version_text
+            .rsplit(['.', '-', '+'])
+            .next()
+            .unwrap_or(version_text);
+        let undotted_to_end =
+            after.is_empty() && !version.pre.contains('.') && !version.build.contains('.');
+        if chosen.is_none() || last.bytes().any(|b| b.is_ascii_digit()) || undotted_to_end {
+            chosen = Some((version, len));
+        }
+    }
+    chosen
+}
+
+// Every prefix of the text that parses as a version, shortest first, with the
+// text after it. A version may end after its patch number, or before any '.',
+// '-' or '+' in what follows, up to the longest pre-release and build metadata
+// that the version grammar allows.
+fn readings(text: &str) -> impl Iterator<Item = (VersionRef<'_>, &str)> {
+    let bytes = text.as_bytes();
+    let end = longest_end(bytes).unwrap_or(0);
+    (0..=end)
+        .filter(move |&i| i == end || matches!(bytes[i], b'.' | b'-' | b'+'))
+        .filter_map(move |i| {
+            let version = parse::version(&text[..i]).ok()?;
+            Some((version, &text[i..]))
+        })
+}
+
+// Where the longest candidate for a version at the start of the text ends, if
+// the text starts with three dot-separated numbers.
+fn longest_end(bytes: &[u8]) -> Option<usize> {
+    let mut end = 0;
+    for i in 0..3 {
+        if i > 0 {
+            if bytes.get(end) != Some(&b'.') {
+                return None;
+            }
+            end += 1;
+        }
+        let digits = bytes[end..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return None;
+        }
+        end += digits;
+    }
+
+    let pre = identifiers_end(bytes, end, b'-');
+    Some(identifiers_end(bytes, pre, b'+'))
+}
+// Where the dot-separated identifiers introduced by `sep` at `start` end, not
+// counting a trailing '.' which would be punctuation rather than part of the
+// version. Returns `start` if there are none.
+fn identifiers_end(bytes: &[u8], start: usize, sep: u8) -> usize {
+    if bytes.get(start) != Some(&sep) {
+        return start;
+    }
+    let len = bytes[start + 1..]
+        .iter()
+        .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'-' || b == b'.')
+        .count();
+    let mut end = start + 1 + len;
+    while end > start + 1 && bytes[end - 1] == b'.' {
+        end -= 1;
+    }
+    if end == start + 1 {
+        start
+    } else {
+        end
+    }
+}
@@ -1,4 +1,5 @@
 use crate::parse::Error;
+use crate::strict::{StrictError, StrictViolation, ViolationKind};
 use core::fmt::{self, Debug, Display};
 
 pub(crate) enum ErrorKind {
@@ -14,6 +15,16 @@ pub(crate) enum ErrorKind {
     WildcardNotTheOnlyComparator(char),
     UnexpectedAfterWildcard,
     ExcessiveComparators,
+    Packed(Packed),
+    ExceededLimit(Limit, usize),
+    InlineCapacity(Position),
+    FixedCapacity(usize),
+    InvalidSortableBytes,
+    #[cfg(feature = "serde")]
+    InvalidCompactBytes,
+    Template(Template),
+    DescribeTag,
+    DescribeEmptyPrerelease,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -25,6 +36,34 @@ pub(crate) enum Position {
     Build,
 }
 
+// A limit set by ParseOptions.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Limit {
+    InputLen,
+    Comparators,
+    Identifiers(Position),
+    IdentifierLen(Position),
+}
+
+// Conversion to or from a packed version or firmware image version.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Packed {
+    Overflow(Position, u8),
+    Length(usize, usize),
+    Bits(Position, u32),
+    Prerelease,
+    HasBuildMetadata,
+    BuildNotNumeric,
+}
+
+// A template of TagScheme or DescribeFormat.
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub(crate) enum Template {
+    Placeholder(usize),
+    TagVersion,
+    TagName,
+}
+
 #[cfg(feature = "std")]
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl std::error::Error for Error {}
@@ -85,6 +124,151 @@ impl Display for Error {
             ErrorKind::ExcessiveComparators => {
                 formatter.write_str("excessive number of version comparators")
             }
+            ErrorKind::Packed(packed) => fmt_packed(formatter, *packed),
+            ErrorKind::ExceededLimit(limit, max) => fmt_limit(formatter, *limit, *max),
+            ErrorKind::InlineCapacity(pos) => {
+                write!(
+                    formatter,
+                    "{} longer than 8 bytes cannot be stored without allocating",
+                    pos,
+                )
+            }
+            ErrorKind::FixedCapacity(capacity) => write!(
+                formatter,
+                "version req exceeds FixedVersionReq capacity of {} comparators",
+                capacity,
+            ),
+            ErrorKind::InvalidSortableBytes => {
+                formatter.write_str("invalid sortable byte encoding of a version")
+            }
+            #[cfg(feature = "serde")]
+            ErrorKind::InvalidCompactBytes => {
+                formatter.write_str("invalid compact binary encoding")
+            }
+            ErrorKind::Template(template) => fmt_template(formatter, *template),
+            ErrorKind::DescribeTag => {
+                formatter.write_str("tag in git describe output does not match the tag scheme")
+            }
+            ErrorKind::DescribeEmptyPrerelease => formatter
+                .write_str("pre-release template of the describe format expands to nothing"),
+        }
+    }
+}
+
+fn fmt_packed(formatter: &mut fmt::Formatter, packed: Packed) -> fmt::Result {
+    match packed {
+        Packed::Overflow(pos, width) => write!(
+            formatter,
+            "value of {} does not fit in {} byte{}",
+            pos,
+            width,
+            if width == 1 { "" } else { "s" },
+        ),
+        Packed::Length(expected, found) => write!(
+            formatter,
+            "packed version layout has {} bytes, found {}",
+            expected, found,
+        ),
+        Packed::Bits(pos, bits) => {
+            write!(formatter, "value of {} does not fit in {} bits", pos, bits)
+        }
+        Packed::Prerelease => {
+            formatter.write_str("pre-release cannot be stored in a packed version")
+        }
+        Packed::HasBuildMetadata => {
+            formatter.write_str("build metadata cannot be stored in a packed version")
+        }
+        Packed::BuildNotNumeric => formatter
+            .write_str("build metadata must be a decimal number to be stored in a packed version"),
+    }
+}
+
+fn fmt_limit(formatter: &mut fmt::Formatter, limit: Limit, max: usize) -> fmt::Result {
+    match limit {
+        Limit::InputLen => write!(
+            formatter,
+            "input is longer than {} bytes, the limit set by max_input_len",
+            max,
+        ),
+        Limit::Comparators => write!(
+            formatter,
+            "version req has more than {} comparators, the limit set by max_comparators",
+            max,
+        ),
+        Limit::Identifiers(pos) => write!(
+            formatter,
+            "{} has more than {} dot-separated identifiers, the limit set by max_identifiers",
+            pos, max,
+        ),
+        Limit::IdentifierLen(pos) => write!(
+            formatter,
+            "{} has an identifier longer than {} bytes, the limit set by max_identifier_len",
+            pos, max,
+        ),
+    }
+}
+
+fn fmt_template(formatter: &mut fmt::Formatter, template: Template) -> fmt::Result {
+    match template {
+        Template::Placeholder(offset) => write!(
+            formatter,
+            "unknown placeholder or unmatched brace at byte {} of template",
+            offset,
+        ),
+        Template::TagVersion => {
+            formatter.write_str("tag template must contain {version} exactly once")
+        }
+        Template::TagName => {
+            formatter.write_str("tag template must not contain {name} more than once")
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for StrictError {}
+
+impl Display for StrictError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if let [violation] = self.violations.as_slice() {
+            return Display::fmt(violation, formatter);
+        }
+        write!(
+            formatter,
+            "{} violations of the semver.org grammar",
+            self.violations.len(),
+        )?;
+        for (i, violation) in self.violations.iter().enumerate() {
+            formatter.write_str(if i == 0 { ": " } else { "; " })?;
+            Display::fmt(violation, formatter)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for StrictViolation {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ViolationKind::Empty => formatter.write_str("empty string, expected a semver version"),
+            ViolationKind::CoreParts(parts) => write!(
+                formatter,
+                "expected 3 dot-separated numbers in version core, found {}",
+                parts,
+            ),
+            ViolationKind::EmptyNumber(pos) => write!(formatter, "empty {}", pos),
+            ViolationKind::NonDigit(pos, ch) | ViolationKind::IllegalCharacter(pos, ch) => {
+                write!(
+                    formatter,
+                    "unexpected character {} in {}",
+                    QuotedChar(ch),
+                    pos,
+                )
+            }
+            ViolationKind::LeadingZero(pos) => write!(formatter, "invalid leading zero in {}", pos),
+            ViolationKind::Overflow(pos) => write!(formatter, "value of {} exceeds u64::MAX", pos),
+            ViolationKind::EmptySegment(pos) => {
+                write!(formatter, "empty identifier segment in {}", pos)
+            }
         }
     }
 }
@@ -110,6 +294,21 @@ impl Debug for Error {
     }
 }
 
+impl Debug for StrictError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_tuple("StrictError")
+            .field(&self.violations)
+            .finish()
+    }
+}
+
+impl Debug for StrictViolation {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "StrictViolation({:?}, \"{}\")", self.span, self)
+    }
+}
+
 struct QuotedChar(char);
 
 impl Display for QuotedChar {
@@ -1,8 +1,11 @@
-use crate::{Comparator, Op, Version, VersionReq};
-
-pub(crate) fn matches_req(req: &VersionReq, ver: &Version) -> bool {
-    for cmp in &req.comparators {
-        if !matches_impl(cmp, ver) {
+use crate::impls::cmp_prerelease;
+use crate::interval::Interval;
+use crate::{Comparator, ComparatorRef, Op, RangePosition, Version, VersionRef, VersionReq};
+use core::cmp::Ordering;
+
+pub(crate) fn matches_req(comparators: &[Comparator], ver: &VersionRef) -> bool {
+    for cmp in comparators {
+        if !matches_impl(&ComparatorRef::from(cmp), ver) {
             return false;
         }
     }
@@ -14,8 +17,8 @@ pub(crate) fn matches_req(req: &VersionReq, ver: &Version) -> bool {
     // If a version has a prerelease tag (for example, 1.2.3-alpha.3) then it
     // will only be allowed to satisfy req if at least one comparator with the
     // same major.minor.patch also has a prerelease tag.
-    for cmp in &req.comparators {
-        if pre_is_compatible(cmp, ver) {
+    for cmp in comparators {
+        if pre_is_compatible(&ComparatorRef::from(cmp), ver) {
             return true;
         }
     }
@@ -23,11 +26,35 @@ pub(crate) fn matches_req(req: &VersionReq, ver: &Version) -> bool {
     false
 }
 
-pub(crate) fn matches_comparator(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn position(req: &VersionReq, ver: &Version) -> RangePosition {
+    if matches_req(&req.comparators, &VersionRef::from(ver)) {
+        return RangePosition::Within;
+    }
+
+    let mut below = false;
+    let mut above = false;
+    for cmp in &req.comparators {
+        let interval = Interval::of(cmp);
+        below |= !interval.is_above_lower(ver);
+        above |= !interval.is_below_upper(ver);
+    }
+
+    match (below, above) {
+        (true, true) => RangePosition::Gap,
+        (true, false) => RangePosition::Below,
+        (false, true) => RangePosition::Above,
+        // Within the bounds of every comparator, yet not matched. Either a
+        // comparator like `>=1.2` excludes the pre-releases of 1.2.x, or no
+        // comparator has a pre-release with the same major.minor.patch.
+        (false, false) => RangePosition::PrereleaseExcluded,
+    }
+}
+
+pub(crate) fn matches_comparator(cmp: &ComparatorRef, ver: &VersionRef) -> bool {
     matches_impl(cmp, ver) && (ver.pre.is_empty() || pre_is_compatible(cmp, ver))
 }
 
-fn matches_impl(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn matches_impl(cmp: &ComparatorRef, ver: &VersionRef) -> bool {
     match cmp.op {
         Op::Exact | Op::Wildcard => matches_exact(cmp, ver),
         Op::Greater => matches_greater(cmp, ver),
@@ -39,7 +66,7 @@ fn matches_impl(cmp: &Comparator, ver: &Version) -> bool {
     }
 }
 
-fn matches_exact(cmp: &Comparator, ver: &Version) -> bool {
+fn matches_exact(cmp: &ComparatorRef, ver: &VersionRef) -> bool {
     if ver.major != cmp.major {
         return false;
     }
@@ -59,7 +86,7 @@ fn matches_exact(cmp: &Comparator, ver: &Version) -> bool {
     ver.pre == cmp.pre
 }
 
-fn matches_greater(cmp: &Comparator, ver: &Version) -> bool {
+fn matches_greater(cmp: &ComparatorRef, ver: &VersionRef) -> bool {
     if ver.major != cmp.major {
         return ver.major > cmp.major;
     }
@@ -82,10 +109,10 @@ fn matches_greater(cmp: &Comparator, ver: &Version) -> bool {
         }
     }
 
-    ver.pre > cmp.pre
+    cmp_prerelease(ver.pre, cmp.pre) == Ordering::Greater
 }
 
-fn matches_less(cmp: &Comparator, ver: &Version) -> bool {
+fn matches_less(cmp: &ComparatorRef, ver: &VersionRef) -> bool {
     if ver.major != cmp.major {
         return ver.major < cmp.major;
     }
@@ -108,10 +135,10 @@ fn matches_less(cmp: &Comparator, ver: &Version) -> bool {
         }
     }
 
-    ver.pre < cmp.pre
+    cmp_prerelease(ver.pre, cmp.pre) == Ordering::Less
 }
 
-fn matches_tilde(cmp: &Comparator, ver: &Version) -> bool {
+fn matches_tilde(cmp: &ComparatorRef, ver: &VersionRef) -> bool {
     if ver.major != cmp.major {
         return false;
     }
@@ -128,10 +155,10 @@ fn matches_tilde(cmp: &Comparator, ver: &Version) -> bool {
         }
     }
 
-    ver.pre >= cmp.pre
+    cmp_prerelease(ver.pre, cmp.pre) != Ordering::Less
 }
 
-fn matches_caret(cmp: &Comparator, ver: &Version) -> bool {
+fn matches_caret(cmp: &ComparatorRef, ver: &VersionRef) -> bool {
     if ver.major != cmp.major {
         return false;
     }
@@ -164,10 +191,10 @@ fn matches_caret(cmp: &Comparator, ver: &Version) -> bool {
         return false;
     }
 
-    ver.pre >= cmp.pre
+    cmp_prerelease(ver.pre, cmp.pre) != Ordering::Less
 }
 
-fn pre_is_compatible(cmp: &Comparator, ver: &Version) -> bool {
+pub(crate) fn pre_is_compatible(cmp: &ComparatorRef, ver: &VersionRef) -> bool {
     cmp.major == ver.major
         && cmp.minor == Some(ver.minor)
         && cmp.patch == Some(ver.patch)
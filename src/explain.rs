@@ -0,0 +1,231 @@
+use crate::eval;
+use crate::interval::Interval;
+use crate::{Comparator, ComparatorRef, Version, VersionRef, VersionReq};
+use alloc::vec::Vec;
+use core::fmt::{self, Display};
+use core::ops::Bound;
+
+/// Report of why a version does or does not match a version requirement.
+///
+/// Produced by [`VersionReq::explain`]. The `Display` impl renders a
+/// multi-line human-readable report.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Version, VersionReq};
+///
+/// let req = VersionReq::parse(">=1.2.3, <1.8.0").unwrap();
+/// let version = Version::parse("1.8.0").unwrap();
+///
+/// let explanation = req.explain(&version);
+/// assert!(!explanation.matches());
+/// assert_eq!(
+///     explanation.to_string(),
+///     "1.8.0 does not match >=1.2.3, <1.8.0\n\
+///      \x20 >=1.2.3: satisfied\n\
+///      \x20 <1.8.0: violated, above the upper bound <1.8.0",
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Explanation<'a> {
+    pub req: &'a VersionReq,
+    pub version: &'a Version,
+    /// The outcome of each comparator of the requirement, in order.
+    pub comparators: Vec<ComparatorExplanation<'a>>,
+    /// The outcome of the pre-release rule.
+    pub prerelease: PrereleaseRule<'a>,
+}
+
+/// Whether one comparator of a version requirement matches a version.
+#[derive(Clone, Debug)]
+pub struct ComparatorExplanation<'a> {
+    pub comparator: &'a Comparator,
+    /// None if the comparator is satisfied by the version.
+    pub violation: Option<Violation>,
+}
+
+/// The way in which a version fails to satisfy a comparator.
+///
+/// Bounds are written as versions without build metadata. An upper bound like
+/// the one implied by `^1.2.3` is `<2.0.0-0`, as opposed to `<2.0.0`, because
+/// the pre-releases of 2.0.0 are above the range too.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum Violation {
+    /// The version is lower than the comparator's lower bound.
+    Below(Bound<Version>),
+    /// The version is higher than the comparator's upper bound.
+    Above(Bound<Version>),
+    /// The version is within the comparator's bounds, but it is a pre-release
+    /// and the comparator only matches releases in that range. This is the
+    /// case for example for `>=1.2` and 1.2.7-alpha, whereas 1.2.7 would
+    /// match.
+    Prerelease,
+}
+
+/// Outcome of the rule that a pre-release version only matches a requirement
+/// containing a comparator with the same major.minor.patch and a pre-release
+/// of its own.
+///
+/// See [`VersionReq::STAR`] for more on this rule.
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub enum PrereleaseRule<'a> {
+    /// The version is not a pre-release, so the rule does not apply.
+    NotApplicable,
+    /// The pre-release is allowed by this comparator.
+    Allowed(&'a Comparator),
+    /// No comparator allows the pre-release.
+    Excluded,
+}
+
+pub(crate) fn explain<'a>(req: &'a VersionReq, ver: &'a Version) -> Explanation<'a> {
+    let comparators = req
+        .comparators
+        .iter()
+        .map(|cmp| ComparatorExplanation {
+            comparator: cmp,
+            violation: violation(cmp, ver),
+        })
+        .collect();
+
+    let prerelease =
+        if ver.pre.is_empty() {
+            PrereleaseRule::NotApplicable
+        } else {
+            match req.comparators.iter().find(|cmp| {
+                eval::pre_is_compatible(&ComparatorRef::from(*cmp), &VersionRef::from(ver))
+            }) {
+                Some(cmp) => PrereleaseRule::Allowed(cmp),
+                None => PrereleaseRule::Excluded,
+            }
+        };
+
+    Explanation {
+        req,
+        version: ver,
+        comparators,
+        prerelease,
+    }
+}
+
+fn violation(cmp: &Comparator, ver: &Version) -> Option<Violation> {
+    if eval::matches_impl(&ComparatorRef::from(cmp), &VersionRef::from(ver)) {
+        return None;
+    }
+
+    let interval = Interval::of(cmp);
+    Some(if !interval.is_above_lower(ver) {
+        Violation::Below(interval.lower)
+    } else if !interval.is_below_upper(ver) {
+        Violation::Above(interval.upper)
+    } else {
+        debug_assert!(interval.excludes_pre(ver));
+        Violation::Prerelease
+    })
+}
+
+impl<'a> Explanation<'a> {
+    /// Whether the version matches the requirement. This is always the same as
+    /// [`VersionReq::matches`].
+    pub fn matches(&self) -> bool {
+        self.comparators
+            .iter()
+            .all(ComparatorExplanation::is_satisfied)
+            && !matches!(self.prerelease, PrereleaseRule::Excluded)
+    }
+
+    /// Whether the pre-release rule is the only reason that the version does
+    /// not match, i.e. every comparator is individually satisfied.
+    pub fn is_excluded_only_by_prerelease_rule(&self) -> bool {
+        self.comparators
+            .iter()
+            .all(ComparatorExplanation::is_satisfied)
+            && matches!(self.prerelease, PrereleaseRule::Excluded)
+    }
+
+    /// The comparators that the version does not satisfy.
+    pub fn violations(&self) -> impl Iterator<Item = &ComparatorExplanation<'a>> {
+        self.comparators.iter().filter(|cmp| !cmp.is_satisfied())
+    }
+}
+
+impl ComparatorExplanation<'_> {
+    pub fn is_satisfied(&self) -> bool {
+        self.violation.is_none()
+    }
+}
+
+impl Display for Explanation<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        let verdict = if self.matches() {
+            "matches"
+        } else {
+            "does not match"
+        };
+        write!(formatter, "{} {} {}", self.version, verdict, self.req)?;
+        for cmp in &self.comparators {
+            write!(formatter, "\n  {}", cmp)?;
+        }
+        match self.prerelease {
+            PrereleaseRule::NotApplicable => {}
+            PrereleaseRule::Allowed(cmp) => {
+                write!(formatter, "\n  pre-release allowed by {}", cmp)?;
+            }
+            PrereleaseRule::Excluded => {
+                write!(
+                    formatter,
+                    "\n  note: pre-release {} is only matched by a requirement that has a \
+                     comparator with version {}.{}.{} and a pre-release of its own",
+                    self.version, self.version.major, self.version.minor, self.version.patch,
+                )?;
+                if self.is_excluded_only_by_prerelease_rule() {
+                    formatter.write_str("; this is the only reason it does not match")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for ComparatorExplanation<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}: ", self.comparator)?;
+        match &self.violation {
+            None => formatter.write_str("satisfied"),
+            Some(Violation::Below(bound)) => {
+                write!(formatter, "violated, below the lower bound ")?;
+                write_bound(formatter, bound, ">=", ">")
+            }
+            Some(Violation::Above(bound)) => {
+                write!(formatter, "violated, above the upper bound ")?;
+                write_bound(formatter, bound, "<=", "<")
+            }
+            Some(Violation::Prerelease) => {
+                write!(
+                    formatter,
+                    "violated, {} does not match pre-releases of ",
+                    self.comparator
+                )?;
+                match self.comparator.minor {
+                    Some(minor) => write!(formatter, "{}.{}.*", self.comparator.major, minor),
+                    None => write!(formatter, "{}.*", self.comparator.major),
+                }
+            }
+        }
+    }
+}
+
+fn write_bound(
+    formatter: &mut fmt::Formatter,
+    bound: &Bound<Version>,
+    inclusive: &str,
+    exclusive: &str,
+) -> fmt::Result {
+    match bound {
+        Bound::Included(version) => write!(formatter, "{}{}", inclusive, version),
+        Bound::Excluded(version) => write!(formatter, "{}{}", exclusive, version),
+        Bound::Unbounded => formatter.write_str("*"),
+    }
+}
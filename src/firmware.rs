@@ -0,0 +1,281 @@
+//! Fixed-width binary version layouts used in firmware image headers.
+//!
+//! Bootloaders such as MCUboot store the version of an image as a handful of
+//! unsigned integers of fixed width, rather than as a string. A [`Layout`]
+//! describes the width in bytes of each of the major, minor, and patch numbers
+//! and of a build number, along with the byte order, and converts between
+//! that packed form and an [`ImageVersion`] holding the same four numbers.
+//!
+//! Everything needed to produce or read a header is a `const fn`, so firmware
+//! can embed its own version at compile time. On the other side, an
+//! `ImageVersion` converts to and from [`Version`] for comparison against
+//! requirements, with the build number stored as the build metadata.
+//!
+//! ```
+//! use semver::firmware::{ImageVersion, Layout};
+//! use semver::{Version, VersionReq};
+//!
+//! // u8 major, u8 minor, u16 revision, u32 build number, little endian.
+//! const HEADER: [u8; 8] = match Layout::MCUBOOT.encode(ImageVersion::new(1, 2, 3, 4)) {
+//!     Ok(bytes) => bytes,
+//!     Err(_) => panic!("version does not fit the MCUboot layout"),
+//! };
+//! assert_eq!(HEADER, [1, 2, 3, 0, 4, 0, 0, 0]);
+//!
+//! let version = Layout::MCUBOOT.decode_version(&HEADER).unwrap();
+//! assert_eq!(version, Version::parse("1.2.3+4").unwrap());
+//! assert!(VersionReq::parse(">=1.2").unwrap().matches(&version));
+//! ```
+
+use crate::error::{ErrorKind, Packed, Position};
+use crate::parse::{self, Error};
+use crate::{BuildMetadata, Prerelease, Version};
+use core::convert::TryFrom;
+
+/// Byte order of the fields of a [`Layout`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Endian {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+/// Width and byte order of each field of a packed version.
+///
+/// The fields are stored one after the other in the order major, minor,
+/// patch, build number, each taking between 0 and 8 bytes. A field of width 0
+/// is absent from the packed form; it decodes as 0, and only 0 can be encoded
+/// into it.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Layout {
+    major: u8,
+    minor: u8,
+    patch: u8,
+    build: u8,
+    endian: Endian,
+}
+
+/// The four numbers stored in a packed version.
+///
+/// Comparison is by major, then minor, then patch, then build number.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct ImageVersion {
+    /// The major version number.
+    pub major: u64,
+    /// The minor version number.
+    pub minor: u64,
+    /// The patch version number, called the revision in MCUboot.
+    pub patch: u64,
+    /// The build number, stored in a `Version` as its build metadata.
+    pub build: u64,
+}
+
+impl Layout {
+    /// The `image_version` struct of an MCUboot image header: u8 major, u8
+    /// minor, u16 revision, u32 build number, little endian.
+    pub const MCUBOOT: Self = Layout::new(1, 1, 2, 4, Endian::Little);
+
+    /// Describe a layout by the width in bytes of each field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any width is greater than 8. In a const context this is a
+    /// compile-time error.
+    pub const fn new(major: u8, minor: u8, patch: u8, build: u8, endian: Endian) -> Self {
+        assert!(
+            major <= 8 && minor <= 8 && patch <= 8 && build <= 8,
+            "field of a packed version layout cannot be wider than 8 bytes",
+        );
+        Layout {
+            major,
+            minor,
+            patch,
+            build,
+            endian,
+        }
+    }
+
+    /// Total number of bytes of the packed form.
+    pub const fn size(self) -> usize {
+        self.major as usize + self.minor as usize + self.patch as usize + self.build as usize
+    }
+
+    /// Byte order of every field.
+    pub const fn endian(self) -> Endian {
+        self.endian
+    }
+
+    /// Pack the numbers of a version into `N` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `N` is not the [size][Layout::size] of the layout, or if any
+    /// number is too large for the width of its field.
+    pub const fn encode<const N: usize>(self, version: ImageVersion) -> Result<[u8; N], Error> {
+        if N != self.size() {
+            return Err(Error::new(ErrorKind::Packed(Packed::Length(
+                self.size(),
+                N,
+            ))));
+        }
+        let fields = self.fields();
+        let values = [version.major, version.minor, version.patch, version.build];
+        let mut bytes = [0u8; N];
+        let mut offset = 0;
+        let mut i = 0;
+        while i < fields.len() {
+            let (width, pos) = fields[i];
+            let value = values[i];
+            if width < 8 && value >> (width as u32 * 8) != 0 {
+                return Err(Error::new(ErrorKind::Packed(Packed::Overflow(pos, width))));
+            }
+            let mut b = 0;
+            while b < width as usize {
+                let shift = match self.endian {
+                    Endian::Little => b,
+                    Endian::Big => width as usize - 1 - b,
+                };
+                bytes[offset + b] = (value >> (shift * 8)) as u8;
+                b += 1;
+            }
+            offset += width as usize;
+            i += 1;
+        }
+        Ok(bytes)
+    }
+
+    /// Unpack the numbers of a version.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the length of `bytes` is not the [size][Layout::size] of the
+    /// layout.
+    pub const fn decode(self, bytes: &[u8]) -> Result<ImageVersion, Error> {
+        if bytes.len() != self.size() {
+            return Err(Error::new(ErrorKind::Packed(Packed::Length(
+                self.size(),
+                bytes.len(),
+            ))));
+        }
+        let fields = self.fields();
+        let mut values = [0u64; 4];
+        let mut offset = 0;
+        let mut i = 0;
+        while i < fields.len() {
+            let (width, _pos) = fields[i];
+            let mut b = 0;
+            while b < width as usize {
+                let shift = match self.endian {
+                    Endian::Little => b,
+                    Endian::Big => width as usize - 1 - b,
+                };
+                values[i] |= (bytes[offset + b] as u64) << (shift * 8);
+                b += 1;
+            }
+            offset += width as usize;
+            i += 1;
+        }
+        Ok(ImageVersion::new(
+            values[0], values[1], values[2], values[3],
+        ))
+    }
+
+    /// Pack a `Version` into `N` bytes. See the `TryFrom<&Version>` impl of
+    /// [`ImageVersion`] for which versions can be represented.
+    pub fn encode_version<const N: usize>(self, version: &Version) -> Result<[u8; N], Error> {
+        self.encode(ImageVersion::try_from(version)?)
+    }
+
+    /// Unpack a `Version`, with the build number as its build metadata.
+    pub fn decode_version(self, bytes: &[u8]) -> Result<Version, Error> {
+        Ok(self.decode(bytes)?.to_version())
+    }
+
+    const fn fields(self) -> [(u8, Position); 4] {
+        [
+            (self.major, Position::Major),
+            (self.minor, Position::Minor),
+            (self.patch, Position::Patch),
+            (self.build, Position::Build),
+        ]
+    }
+}
+
+impl ImageVersion {
+    /// Create from the four numbers, in the order they are stored.
+    pub const fn new(major: u64, minor: u64, patch: u64, build: u64) -> Self {
+        ImageVersion {
+            major,
+            minor,
+            patch,
+            build,
+        }
+    }
+
+    /// Convert to a `Version` whose build metadata is the build number, or
+    /// empty if the build number is 0.
+    pub fn to_version(&self) -> Version {
+        let mut build = BuildMetadata::EMPTY;
+        if self.build != 0 {
+            let mut digits = [0u8; 20];
+            let mut start = digits.len();
+            let mut n = self.build;
+            while n != 0 {
+                start -= 1;
+                digits[start] = b'0' + (n % 10) as u8;
+                n /= 10;
+            }
+            // SAFETY: digits[start..] holds only ASCII digits.
+            let string = unsafe { core::str::from_utf8_unchecked(&digits[start..]) };
+            build.identifier = parse::new_identifier(string);
+        }
+        Version {
+            major: self.major,
+            minor: self.minor,
+            patch: self.patch,
+            pre: Prerelease::EMPTY,
+            build,
+        }
+    }
+}
+
+/// A version has a packed form if it has no pre-release, and its build
+/// metadata is either empty (build number 0) or a decimal number without
+/// leading zeros.
+impl TryFrom<&Version> for ImageVersion {
+    type Error = Error;
+
+    fn try_from(version: &Version) -> Result<Self, Self::Error> {
+        if !version.pre.is_empty() {
+            return Err(Error::new(ErrorKind::Packed(Packed::Prerelease)));
+        }
+        let build = version.build.as_str();
+        let numeric = !build.is_empty() && build.bytes().all(|b| b.is_ascii_digit());
+        if !build.is_empty() && (!numeric || build.len() > 1 && build.starts_with('0')) {
+            return Err(Error::new(ErrorKind::Packed(Packed::BuildNotNumeric)));
+        }
+        let mut number = 0u64;
+        for digit in build.bytes() {
+            number = match number
+                .checked_mul(10)
+                .and_then(|n| n.checked_add(u64::from(digit - b'0')))
+            {
+                Some(number) => number,
+                None => return Err(Error::new(ErrorKind::Overflow(Position::Build))),
+            };
+        }
+        Ok(ImageVersion::new(
+            version.major,
+            version.minor,
+            version.patch,
+            number,
+        ))
+    }
+}
+
+impl From<ImageVersion> for Version {
+    fn from(version: ImageVersion) -> Self {
+        version.to_version()
+    }
+}
@@ -0,0 +1,102 @@
+use crate::parse::Error;
+use crate::{eval, Comparator, Op, Prerelease, Version, VersionRef};
+use core::array;
+use core::str::FromStr;
+
+/// **SemVer version requirement** with storage for at most `N` comparators,
+/// for use without allocating.
+///
+/// This accepts the same syntax and has the same matching semantics as
+/// [`VersionReq`], but holds its comparators in an array instead of a `Vec`.
+/// Parsing a requirement with more than `N` comparators fails with an error
+/// instead of allocating, and so does a comparator whose pre-release is longer
+/// than the 8 bytes that [`Prerelease`] stores inline. See also
+/// [`Version::parse_inline`].
+///
+/// [`VersionReq`]: crate::VersionReq
+///
+/// # Example
+///
+/// ```
+/// use semver::{FixedVersionReq, Version};
+///
+/// let req = FixedVersionReq::<4>::parse(">=1.2.3, <1.8.0").unwrap();
+/// assert_eq!(req.comparators().len(), 2);
+/// assert!(req.matches(&Version::parse("1.7.5").unwrap()));
+///
+/// // Too many comparators for the capacity.
+/// assert!(FixedVersionReq::<1>::parse(">=1.2.3, <1.8.0").is_err());
+/// ```
+#[derive(Clone)]
+pub struct FixedVersionReq<const N: usize = 32> {
+    len: usize,
+    // Only the first len are meaningful. The rest hold PLACEHOLDER.
+    comparators: [Comparator; N],
+}
+
+const PLACEHOLDER: Comparator = Comparator {
+    op: Op::Caret,
+    major: 0,
+    minor: None,
+    patch: None,
+    pre: Prerelease::EMPTY,
+};
+
+impl<const N: usize> FixedVersionReq<N> {
+    /// A requirement with no comparators, equivalent to `*`. See
+    /// [`VersionReq::STAR`].
+    ///
+    /// [`VersionReq::STAR`]: crate::VersionReq::STAR
+    pub fn new() -> Self {
+        FixedVersionReq {
+            len: 0,
+            comparators: array::from_fn(|_| PLACEHOLDER),
+        }
+    }
+
+    /// Create `FixedVersionReq` by parsing from string representation.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the syntax errors reported by [`VersionReq::parse`],
+    /// parsing fails if the requirement has more than `N` comparators, or if a
+    /// pre-release is longer than 8 bytes.
+    ///
+    /// [`VersionReq::parse`]: crate::VersionReq::parse
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        FixedVersionReq::from_str(text)
+    }
+
+    pub fn comparators(&self) -> &[Comparator] {
+        &self.comparators[..self.len]
+    }
+
+    /// Append a comparator, or give it back if the requirement is already at
+    /// capacity.
+    pub fn push(&mut self, comparator: Comparator) -> Result<(), Comparator> {
+        if self.len == N {
+            return Err(comparator);
+        }
+        self.comparators[self.len] = comparator;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Evaluate whether the given `Version` satisfies the version requirement
+    /// described by `self`.
+    pub fn matches(&self, version: &Version) -> bool {
+        eval::matches_req(self.comparators(), &VersionRef::from(version))
+    }
+
+    /// Evaluate whether the given borrowed version satisfies the version
+    /// requirement described by `self`.
+    pub fn matches_ref(&self, version: &VersionRef) -> bool {
+        eval::matches_req(self.comparators(), version)
+    }
+}
+
+impl<const N: usize> Default for FixedVersionReq<N> {
+    fn default() -> Self {
+        FixedVersionReq::new()
+    }
+}
@@ -0,0 +1,247 @@
+// Building blocks shared by the arbitrary, proptest, and quickcheck
+// integrations. Each of those draws the random choices in its own way and
+// passes them in here, so that all three produce values with the same shape.
+
+use crate::interval;
+use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Bound;
+
+pub(crate) const OPS: [Op; 8] = [
+    Op::Exact,
+    Op::Greater,
+    Op::GreaterEq,
+    Op::Less,
+    Op::LessEq,
+    Op::Tilde,
+    Op::Caret,
+    Op::Wildcard,
+];
+
+// Characters permitted in pre-release and build metadata identifiers, with
+// the non-digits first.
+const IDENTIFIER_CHARS: &[u8] = b"-ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const NON_DIGITS: usize = IDENTIFIER_CHARS.len() - 10;
+
+/// Random choices making up one identifier.
+#[derive(Clone, Debug)]
+pub(crate) enum Identifier {
+    Numeric(u64),
+    // Indices into IDENTIFIER_CHARS, of which the first is reduced modulo
+    // NON_DIGITS so that the identifier is not entirely digits.
+    Alphanumeric(Vec<u8>),
+}
+
+pub(crate) fn dotted(identifiers: impl IntoIterator<Item = Identifier>) -> String {
+    let mut string = String::new();
+    for identifier in identifiers {
+        push_identifier(&mut string, &identifier);
+    }
+    string
+}
+
+fn push_identifier(string: &mut String, identifier: &Identifier) {
+    if !string.is_empty() {
+        string.push('.');
+    }
+    match identifier {
+        Identifier::Numeric(number) => string.push_str(&number.to_string()),
+        Identifier::Alphanumeric(chars) => {
+            let (&first, rest) = chars.split_first().unwrap_or((&0, &[]));
+            string.push(IDENTIFIER_CHARS[first as usize % NON_DIGITS] as char);
+            for &ch in rest {
+                string.push(IDENTIFIER_CHARS[ch as usize % IDENTIFIER_CHARS.len()] as char);
+            }
+        }
+    }
+}
+
+pub(crate) fn prerelease(string: &str) -> Prerelease {
+    Prerelease::new(string).unwrap()
+}
+
+pub(crate) fn build_metadata(string: &str) -> BuildMetadata {
+    BuildMetadata::new(string).unwrap()
+}
+
+// Fill in a comparator from independently chosen parts, dropping whichever
+// parts the parser would never produce in that combination.
+pub(crate) fn comparator(
+    op: Op,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+    pre: Prerelease,
+) -> Comparator {
+    let patch = if minor.is_none() || op == Op::Wildcard {
+        None
+    } else {
+        patch
+    };
+    let pre = if patch.is_none() {
+        Prerelease::EMPTY
+    } else {
+        pre
+    };
+    Comparator {
+        op,
+        major,
+        minor,
+        patch,
+        pre,
+    }
+}
+
+/// Versions near the edges of what a requirement matches, on either side.
+pub(crate) struct Seeds {
+    pub matching: Vec<Version>,
+    pub not_matching: Vec<Version>,
+}
+
+pub(crate) fn seeds(req: &VersionReq) -> Seeds {
+    let mut candidates = Vec::new();
+    let zero = Version::new(0, 0, 0);
+    let max = Version::new(u64::MAX, u64::MAX, u64::MAX);
+    candidates.push(with_pre(&zero, "0"));
+    candidates.push(zero);
+    candidates.push(with_pre(&max, "0"));
+    candidates.push(max);
+
+    // The least version above the lower bound is a match if any version that
+    // is not a pre-release is; likewise the least pre-release of each
+    // major.minor.patch that the requirement allows pre-releases of.
+    let compiled = req.compile();
+    for bound in [&compiled.lower, &compiled.upper] {
+        if let Bound::Included(v) | Bound::Excluded(v) = bound {
+            neighbors(v, &mut candidates);
+        }
+    }
+    for cmp in &req.comparators {
+        let minor = cmp.minor.unwrap_or(0);
+        let patch = cmp.patch.unwrap_or(0);
+        let v = Version {
+            pre: cmp.pre.clone(),
+            ..Version::new(cmp.major, minor, patch)
+        };
+        neighbors(&v, &mut candidates);
+        if let Some(next) = cmp.major.checked_add(1) {
+            neighbors(&interval::floor(next, 0, 0), &mut candidates);
+        }
+        if let Some(next) = minor.checked_add(1) {
+            neighbors(&interval::floor(cmp.major, next, 0), &mut candidates);
+        }
+    }
+
+    candidates.sort();
+    candidates.dedup();
+    let (matching, not_matching) = candidates.into_iter().partition(|v| req.matches(v));
+    Seeds {
+        matching,
+        not_matching,
+    }
+}
+
+// Push a version along with those immediately around it.
+fn neighbors(v: &Version, out: &mut Vec<Version>) {
+    let release = Version::new(v.major, v.minor, v.patch);
+    if !v.pre.is_empty() {
+        let mut pre = v.pre.as_str().to_string();
+        pre.push_str(".0");
+        out.push(with_pre(&release, &pre));
+        out.push(v.clone());
+    }
+    out.push(with_pre(&release, "0"));
+    out.push(release.clone());
+    for delta in [Delta::Patch, Delta::Minor, Delta::Major] {
+        for up in [true, false] {
+            if let Some(v) = delta.apply(&release, up, 1) {
+                out.push(with_pre(&v, "0"));
+                out.push(v);
+            }
+        }
+    }
+}
+
+fn with_pre(v: &Version, pre: &str) -> Version {
+    Version {
+        pre: prerelease(pre),
+        ..v.clone()
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Delta {
+    Patch,
+    Minor,
+    Major,
+}
+
+impl Delta {
+    fn apply(self, v: &Version, up: bool, amount: u64) -> Option<Version> {
+        let step = |n: u64| {
+            if up {
+                n.checked_add(amount)
+            } else {
+                n.checked_sub(amount)
+            }
+        };
+        Some(match self {
+            Delta::Patch => Version::new(v.major, v.minor, step(v.patch)?),
+            Delta::Minor => Version::new(v.major, step(v.minor)?, 0),
+            Delta::Major => Version::new(step(v.major)?, 0, 0),
+        })
+    }
+}
+
+/// Random choices for moving a seed version somewhere nearby.
+#[derive(Debug)]
+pub(crate) struct Perturbation {
+    pub kind: u8,
+    pub amount: u64,
+    pub pre: Prerelease,
+    pub build: BuildMetadata,
+}
+
+impl Perturbation {
+    fn apply(&self, seed: &Version) -> Version {
+        let deltas = [Delta::Patch, Delta::Minor, Delta::Major];
+        let mut v = match self.kind % 8 {
+            kind @ 0..=5 => {
+                let delta = deltas[kind as usize % 3];
+                match delta.apply(seed, kind < 3, self.amount) {
+                    Some(v) => v,
+                    None => seed.clone(),
+                }
+            }
+            6 => Version {
+                pre: self.pre.clone(),
+                ..seed.clone()
+            },
+            _ => seed.clone(),
+        };
+        v.build = self.build.clone();
+        v
+    }
+}
+
+// Pick one of the seeds and perturb it, keeping the perturbation only if it
+// stays on the same side of the requirement.
+pub(crate) fn pick(
+    req: &VersionReq,
+    seeds: &[Version],
+    want_match: bool,
+    index: usize,
+    perturbation: &Perturbation,
+) -> Version {
+    let seed = &seeds[index % seeds.len()];
+    let v = perturbation.apply(seed);
+    if req.matches(&v) == want_match {
+        v
+    } else {
+        Version {
+            build: v.build,
+            ..seed.clone()
+        }
+    }
+}
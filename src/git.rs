@@ -0,0 +1,175 @@
+use crate::error::{ErrorKind, Position};
+use crate::parse::Error;
+use crate::tag;
+use crate::{BuildMetadata, Prerelease, TagScheme, Version};
+use alloc::string::String;
+use core::fmt::Write;
+
+/// How [`Version::from_git_describe_with`] forms a development version.
+///
+/// The pre-release and build metadata are templates in which `{count}`
+/// stands for the number of commits since the tag, and `{hash}` for the
+/// abbreviated commit hash without git's `g` prefix.
+///
+/// For the versions to sort by commit count, the pre-release template needs
+/// to contain `{count}` as an identifier of its own, as in the default
+/// `dev.{count}`.
+///
+/// # Example
+///
+/// ```
+/// use semver::{DescribeFormat, TagScheme, Version};
+///
+/// const FORMAT: DescribeFormat = DescribeFormat::new()
+///     .tag_scheme(TagScheme::NAME_V_VERSION)
+///     .pre("nightly.{count}")
+///     .build("sha.{hash}")
+///     .dirty("modified");
+///
+/// let version = Version::from_git_describe_with("foo-v1.2.3-14-gabc1234-dirty", &FORMAT).unwrap();
+/// assert_eq!(version.to_string(), "1.2.4-nightly.14+sha.abc1234.modified");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DescribeFormat<'a> {
+    tag_scheme: Option<TagScheme<'a>>,
+    pre: &'a str,
+    build: &'a str,
+    dirty: &'a str,
+}
+
+impl<'a> DescribeFormat<'a> {
+    /// The format used by [`Version::from_git_describe`]: tags that are a
+    /// version optionally preceded by `v`, pre-release `dev.{count}`, build
+    /// metadata `g{hash}`, and `dirty` for uncommitted changes.
+    pub const fn new() -> Self {
+        DescribeFormat {
+            tag_scheme: None,
+            pre: "dev.{count}",
+            build: "g{hash}",
+            dirty: "dirty",
+        }
+    }
+
+    /// How to find the version in the tag. If the scheme has a `{name}`, any
+    /// crate name is accepted.
+    #[must_use]
+    pub const fn tag_scheme(mut self, scheme: TagScheme<'a>) -> Self {
+        self.tag_scheme = Some(scheme);
+        self
+    }
+
+    /// Template of the pre-release, or of the identifiers appended to it if
+    /// the tag is a pre-release itself. Forming a version fails if the
+    /// template expands to nothing, such as `{hash}` for output of `git
+    /// describe` without a commit hash, since the development version would
+    /// otherwise be indistinguishable from a release.
+    #[must_use]
+    pub const fn pre(mut self, template: &'a str) -> Self {
+        self.pre = template;
+        self
+    }
+
+    /// Template of the build metadata. Left out if the output of `git
+    /// describe` has no commit hash.
+    #[must_use]
+    pub const fn build(mut self, template: &'a str) -> Self {
+        self.build = template;
+        self
+    }
+
+    /// Identifier appended to the build metadata when the working tree has
+    /// uncommitted changes.
+    #[must_use]
+    pub const fn dirty(mut self, identifier: &'a str) -> Self {
+        self.dirty = identifier;
+        self
+    }
+}
+
+impl Default for DescribeFormat<'_> {
+    fn default() -> Self {
+        DescribeFormat::new()
+    }
+}
+
+pub(crate) fn from_describe(text: &str, format: &DescribeFormat) -> Result<Version, Error> {
+    let (text, dirty) = match text.strip_suffix("-dirty") {
+        Some(text) => (text, true),
+        None => (text, false),
+    };
+    let (tag, count, hash) = match split_commit(text) {
+        Some((tag, count, hash)) => (tag, count, Some(hash)),
+        None => (text, 0, None),
+    };
+
+    let mut version = match format.tag_scheme {
+        Some(scheme) => match scheme.split(tag).into_iter().next() {
+            Some((_name, version)) => version,
+            None => return Err(Error::new(ErrorKind::DescribeTag)),
+        },
+        None => Version::parse(tag.strip_prefix('v').unwrap_or(tag))?,
+    };
+    if count == 0 && !dirty {
+        return Ok(version);
+    }
+
+    // A release tag is followed by pre-releases of the next patch version,
+    // and a pre-release tag by longer pre-releases of the same version.
+    let mut pre = String::new();
+    if version.pre.is_empty() {
+        version.patch = match version.patch.checked_add(1) {
+            Some(patch) => patch,
+            None => return Err(Error::new(ErrorKind::Overflow(Position::Patch))),
+        };
+    } else {
+        pre.push_str(version.pre.as_str());
+        pre.push('.');
+    }
+    let len = pre.len();
+    expand(&mut pre, format.pre, count, hash.unwrap_or(""))?;
+    if pre.len() == len {
+        return Err(Error::new(ErrorKind::DescribeEmptyPrerelease));
+    }
+    version.pre = Prerelease::new(&pre)?;
+
+    let mut build = String::new();
+    if let Some(hash) = hash {
+        expand(&mut build, format.build, count, hash)?;
+    }
+    if dirty {
+        if !build.is_empty() {
+            build.push('.');
+        }
+        build.push_str(format.dirty);
+    }
+    version.build = BuildMetadata::new(&build)?;
+    Ok(version)
+}
+
+// Split `<tag>-<count>-g<hash>` into its parts.
+fn split_commit(text: &str) -> Option<(&str, u64, &str)> {
+    let (rest, hash) = text.rsplit_once("-g")?;
+    let (tag, count) = rest.rsplit_once('-')?;
+    if hash.is_empty() || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    if count.is_empty() || !count.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    Some((tag, count.parse().ok()?, hash))
+}
+
+fn expand(out: &mut String, template: &str, count: u64, hash: &str) -> Result<(), Error> {
+    let (literals, placeholders) = tag::split_template(template, &["{count}", "{hash}"])?;
+    for (i, literal) in literals.iter().enumerate() {
+        out.push_str(literal);
+        match placeholders.get(i) {
+            Some(0) => {
+                let _ = write!(out, "{}", count);
+            }
+            Some(_) => out.push_str(hash),
+            None => {}
+        }
+    }
+    Ok(())
+}
@@ -34,8 +34,8 @@
 // longer than 8 bytes.
 //
 // To differentiate the inline representation from the heap allocated long
-// representation, we'll allocate heap pointers with 2-byte alignment so that
-// they are guaranteed to have an unset least significant bit. Then in the repr
+// representation, we'll allocate heap pointers with 4-byte alignment so that
+// they are guaranteed to have two unset least significant bits. Then in the repr
 // we store for pointers, we rotate a 1 into the most significant bit of the
 // most significant byte, which is never set for an ASCII byte.
 //
@@ -45,9 +45,16 @@
 //
 // Heap allocated repr:
 //
-//     1ppppppp pppppppp pppppppp pppppppp pppppppp pppppppp pppppppp pppppppp 0
+//     1ppppppp pppppppp pppppppp pppppppp pppppppp pppppppp pppppppp ppppppps 0
 //     ^ most significant bit   least significant bit of orig ptr, rotated out ^
 //
+// The second least significant bit of the original pointer, s, is set if the
+// allocation is shared by reference count, as done by VersionInterner. Such an
+// allocation is preceded by an AtomicUsize count of the Identifiers pointing
+// to it, which cloning increments instead of copying the string, and the last
+// one to be dropped frees it. Identical strings from the same interner share
+// one pointer, which makes comparing them a single pointer comparison.
+//
 // Since the most significant bit doubles as a sign bit for the similarly sized
 // signed integer type, the CPU has an efficient instruction for inspecting it,
 // meaning we can differentiate between an inline repr and a heap allocated repr
@@ -72,6 +79,8 @@ use core::num::{NonZeroU64, NonZeroUsize};
 use core::ptr::{self, NonNull};
 use core::slice;
 use core::str;
+#[cfg(feature = "std")]
+use core::sync::atomic::{self, AtomicUsize, Ordering};
 
 const PTR_BYTES: usize = mem::size_of::<NonNull<u8>>();
 
@@ -118,7 +127,7 @@ impl Identifier {
             9..=0xff_ffff_ffff_ffff => {
                 // SAFETY: len is in a range that does not contain 0.
                 let size = bytes_for_varint(unsafe { NonZeroUsize::new_unchecked(len) }) + len;
-                let align = 2;
+                let align = 4;
                 // On 32-bit and 16-bit architecture, check for size overflowing
                 // isize::MAX. Making an allocation request bigger than this to
                 // the allocator is considered UB. All allocations (including
@@ -164,6 +173,18 @@ impl Identifier {
         }
     }
 
+    // Const counterpart of new_unchecked for strings short enough to use the
+    // inline repr, which is the only one constructible without allocating.
+    //
+    // SAFETY: bytes must be 1 to 8 ASCII characters other than \0, followed by
+    // \0 padding.
+    pub(crate) const unsafe fn new_inline_unchecked(
+        bytes: [u8; mem::size_of::<Identifier>()],
+    ) -> Self {
+        // SAFETY: the head field is nonzero because the first byte is nonzero.
+        unsafe { mem::transmute::<[u8; mem::size_of::<Identifier>()], Identifier>(bytes) }
+    }
+
     pub(crate) fn is_empty(&self) -> bool {
         // `cmp rdi, -1` -- basically: `repr as i64 == -1`
         let empty = Self::empty();
@@ -200,6 +221,49 @@ impl Identifier {
     pub(crate) fn ptr_eq(&self, rhs: &Self) -> bool {
         self.head == rhs.head && self.tail == rhs.tail
     }
+
+    // Whether the repr is a heap allocation shared by reference count.
+    // Meaningless for the empty and inline reprs.
+    fn is_shared(&self) -> bool {
+        (self.head.as_ptr() as usize).rotate_left(1) & SHARED_BIT != 0
+    }
+
+    // Copy the string into an allocation shared by reference count, so that
+    // cloning the result does not copy it again. Returns the empty, inline and
+    // already shared reprs unchanged.
+    #[cfg(feature = "std")]
+    #[allow(clippy::cast_ptr_alignment)] // the header is aligned for AtomicUsize
+    pub(crate) fn into_shared(self) -> Self {
+        if self.is_empty_or_inline() || self.is_shared() {
+            return self;
+        }
+        let ptr = repr_to_ptr(self.head);
+        // SAFETY: ptr is one of our own heap allocations.
+        let len = unsafe { decode_len(ptr) };
+        let size = bytes_for_varint(len) + len.get();
+        // SAFETY: align is not zero and a power of two, and size is no bigger
+        // than an existing allocation plus a few bytes, as for new_unchecked.
+        let layout =
+            unsafe { Layout::from_size_align_unchecked(SHARED_HEADER + size, SHARED_HEADER) };
+        // SAFETY: layout's size is nonzero.
+        let base = unsafe { alloc(layout) };
+        if base.is_null() {
+            handle_alloc_error(layout);
+        }
+        // SAFETY: base is aligned for an AtomicUsize, which fits in the header,
+        // and the varint and string fit after the header. The new allocation
+        // cannot overlap the previous one.
+        let shared = unsafe {
+            base.cast::<AtomicUsize>().write(AtomicUsize::new(1));
+            let shared = base.add(SHARED_HEADER);
+            ptr::copy_nonoverlapping(ptr, shared, size);
+            shared
+        };
+        Identifier {
+            head: ptr_to_shared_repr(shared),
+            tail: [0; TAIL_BYTES],
+        }
+    }
 }
 
 impl Clone for Identifier {
@@ -209,12 +273,19 @@ impl Clone for Identifier {
                 head: self.head,
                 tail: self.tail,
             }
+        } else if self.is_shared() {
+            // SAFETY: the repr is a shared heap allocation.
+            unsafe { retain_shared(repr_to_ptr(self.head)) };
+            Identifier {
+                head: self.head,
+                tail: self.tail,
+            }
         } else {
             let ptr = repr_to_ptr(self.head);
             // SAFETY: ptr is one of our own heap allocations.
             let len = unsafe { decode_len(ptr) };
             let size = bytes_for_varint(len) + len.get();
-            let align = 2;
+            let align = 4;
             // SAFETY: align is not zero, align is a power of two, and rounding
             // size up to align does not overflow isize::MAX. This is just
             // duplicating a previous allocation where all of these guarantees
@@ -242,11 +313,17 @@ impl Drop for Identifier {
         if self.is_empty_or_inline() {
             return;
         }
+        if self.is_shared() {
+            // SAFETY: the repr is a shared heap allocation, and this is one of
+            // the Identifiers counted by it.
+            unsafe { release_shared(repr_to_ptr_mut(self.head)) };
+            return;
+        }
         let ptr = repr_to_ptr_mut(self.head);
         // SAFETY: ptr is one of our own heap allocations.
         let len = unsafe { decode_len(ptr) };
         let size = bytes_for_varint(len) + len.get();
-        let align = 2;
+        let align = 4;
         // SAFETY: align is not zero, align is a power of two, and rounding
         // size up to align does not overflow isize::MAX. These guarantees were
         // made when originally allocating this memory.
@@ -282,7 +359,16 @@ fn ptr_to_repr(original: *mut u8) -> NonNull<u8> {
     // `mov eax, 1`
     // `shld rax, rdi, 63`
     let modified = (original as usize | 1).rotate_right(1);
+    repr_with_address(original, modified)
+}
+
+#[cfg(feature = "std")]
+fn ptr_to_shared_repr(original: *mut u8) -> NonNull<u8> {
+    let modified = (original as usize | SHARED_BIT | 1).rotate_right(1);
+    repr_with_address(original, modified)
+}
 
+fn repr_with_address(original: *mut u8, modified: usize) -> NonNull<u8> {
     // `original + (modified - original)`, but being mindful of provenance.
     let diff = modified.wrapping_sub(original as usize);
     let modified = original.wrapping_add(diff);
@@ -292,13 +378,73 @@ fn ptr_to_repr(original: *mut u8) -> NonNull<u8> {
     unsafe { NonNull::new_unchecked(modified) }
 }
 
+// Set in the original pointer of allocations shared by reference count.
+const SHARED_BIT: usize = 2;
+
+// Size and alignment of the reference count in front of a shared allocation,
+// at least 4 so that the string after it keeps the alignment of the repr.
+#[cfg(feature = "std")]
+const SHARED_HEADER: usize = if mem::size_of::<AtomicUsize>() > 4 {
+    mem::size_of::<AtomicUsize>()
+} else {
+    4
+};
+
+// SAFETY: ptr must be a shared heap allocation.
+#[cfg(feature = "std")]
+#[allow(clippy::cast_ptr_alignment)] // the header is aligned for AtomicUsize
+unsafe fn retain_shared(ptr: *const u8) {
+    // SAFETY: the reference count precedes the allocation.
+    let count = unsafe { &*ptr.sub(SHARED_HEADER).cast::<AtomicUsize>() };
+    // As in Arc, a new reference can only be made from an existing one, so no
+    // synchronization is needed, and a count approaching overflow can only be
+    // the result of leaking Identifiers on purpose.
+    if count.fetch_add(1, Ordering::Relaxed) > isize::MAX as usize {
+        std::process::abort();
+    }
+}
+
+// SAFETY: ptr must be a shared heap allocation, and the caller must be giving
+// up one of the references counted by it.
+#[cfg(feature = "std")]
+#[allow(clippy::cast_ptr_alignment)] // the header is aligned for AtomicUsize
+unsafe fn release_shared(ptr: *mut u8) {
+    // SAFETY: the reference count precedes the allocation.
+    let base = unsafe { ptr.sub(SHARED_HEADER) };
+    let count = unsafe { &*base.cast::<AtomicUsize>() };
+    if count.fetch_sub(1, Ordering::Release) != 1 {
+        return;
+    }
+    // Synchronize with the other releases before freeing, as in Arc.
+    atomic::fence(Ordering::Acquire);
+    // SAFETY: ptr is one of our own heap allocations.
+    let len = unsafe { decode_len(ptr) };
+    let size = SHARED_HEADER + bytes_for_varint(len) + len.get();
+    // SAFETY: the same layout as in into_shared.
+    let layout = unsafe { Layout::from_size_align_unchecked(size, SHARED_HEADER) };
+    // SAFETY: base was allocated by into_shared with this layout, and this was
+    // the last reference to it.
+    unsafe { dealloc(base, layout) }
+}
+
+// Only into_shared produces shared reprs.
+#[cfg(not(feature = "std"))]
+unsafe fn retain_shared(_ptr: *const u8) {
+    unreachable!()
+}
+
+#[cfg(not(feature = "std"))]
+unsafe fn release_shared(_ptr: *mut u8) {
+    unreachable!()
+}
+
 // Shift out the 1 previously placed into the most significant bit of the least
-// significant byte. Shift in a low 0 bit to reconstruct the original 2-byte
-// aligned pointer.
+// significant byte. Shift in a low 0 bit and clear the shared bit to
+// reconstruct the original 4-byte aligned pointer.
 fn repr_to_ptr(modified: NonNull<u8>) -> *const u8 {
     // `lea rax, [rdi + rdi]`
     let modified = modified.as_ptr();
-    let original = (modified as usize) << 1;
+    let original = (modified as usize) << 1 & !SHARED_BIT;
 
     // `modified + (original - modified)`, but being mindful of provenance.
     let diff = original.wrapping_sub(modified as usize);
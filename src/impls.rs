@@ -1,5 +1,8 @@
 use crate::identifier::Identifier;
-use crate::{BuildMetadata, Comparator, Prerelease, VersionReq};
+use crate::{
+    BuildMetadata, Comparator, FixedVersionReq, Precedence, Prerelease, Version, VersionRef,
+    VersionReq,
+};
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
@@ -52,55 +55,7 @@ impl Ord for Prerelease {
         if self.identifier.ptr_eq(&rhs.identifier) {
             return Ordering::Equal;
         }
-
-        match self.is_empty() {
-            // A real release compares greater than prerelease.
-            true => return Ordering::Greater,
-            // Prerelease compares less than the real release.
-            false if rhs.is_empty() => return Ordering::Less,
-            false => {}
-        }
-
-        let lhs = self.as_str().split('.');
-        let mut rhs = rhs.as_str().split('.');
-
-        for lhs in lhs {
-            let Some(rhs) = rhs.next() else {
-                // Spec: "A larger set of pre-release fields has a higher
-                // precedence than a smaller set, if all of the preceding
-                // identifiers are equal."
-                return Ordering::Greater;
-            };
-
-            let string_cmp = || Ord::cmp(lhs, rhs);
-            let is_ascii_digit = |b: u8| b.is_ascii_digit();
-            let ordering = match (
-                lhs.bytes().all(is_ascii_digit),
-                rhs.bytes().all(is_ascii_digit),
-            ) {
-                // Respect numeric ordering, for example 99 < 100. Spec says:
-                // "Identifiers consisting of only digits are compared
-                // numerically."
-                (true, true) => Ord::cmp(&lhs.len(), &rhs.len()).then_with(string_cmp),
-                // Spec: "Numeric identifiers always have lower precedence than
-                // non-numeric identifiers."
-                (true, false) => return Ordering::Less,
-                (false, true) => return Ordering::Greater,
-                // Spec: "Identifiers with letters or hyphens are compared
-                // lexically in ASCII sort order."
-                (false, false) => string_cmp(),
-            };
-
-            if ordering != Ordering::Equal {
-                return ordering;
-            }
-        }
-
-        if rhs.next().is_none() {
-            Ordering::Equal
-        } else {
-            Ordering::Less
-        }
+        cmp_prerelease(self.as_str(), rhs.as_str())
     }
 }
 
@@ -109,43 +64,76 @@ impl Ord for BuildMetadata {
         if self.identifier.ptr_eq(&rhs.identifier) {
             return Ordering::Equal;
         }
+        cmp_build_metadata(self.as_str(), rhs.as_str())
+    }
+}
 
-        let lhs = self.as_str().split('.');
-        let mut rhs = rhs.as_str().split('.');
-
-        for lhs in lhs {
-            let Some(rhs) = rhs.next() else {
-                return Ordering::Greater;
-            };
-
-            let is_ascii_digit = |b: u8| b.is_ascii_digit();
-            let ordering = match (
-                lhs.bytes().all(is_ascii_digit),
-                rhs.bytes().all(is_ascii_digit),
-            ) {
-                (true, true) => {
-                    // 0 < 00 < 1 < 01 < 001 < 2 < 02 < 002 < 10
-                    let lhval = lhs.trim_start_matches('0');
-                    let rhval = rhs.trim_start_matches('0');
-                    Ord::cmp(&lhval.len(), &rhval.len())
-                        .then_with(|| Ord::cmp(lhval, rhval))
-                        .then_with(|| Ord::cmp(&lhs.len(), &rhs.len()))
-                }
-                (true, false) => return Ordering::Less,
-                (false, true) => return Ordering::Greater,
-                (false, false) => Ord::cmp(lhs, rhs),
-            };
-
-            if ordering != Ordering::Equal {
-                return ordering;
-            }
-        }
+impl PartialOrd for VersionRef<'_> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
 
-        if rhs.next().is_none() {
-            Ordering::Equal
-        } else {
-            Ordering::Less
-        }
+impl Ord for VersionRef<'_> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.cmp_precedence(rhs)
+            .then_with(|| cmp_build_metadata(self.build, rhs.build))
+    }
+}
+
+impl PartialEq for Precedence<Version> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmp(rhs) == Ordering::Equal
+    }
+}
+
+impl Eq for Precedence<Version> {}
+
+impl PartialOrd for Precedence<Version> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for Precedence<Version> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.0.cmp_precedence(&rhs.0)
+    }
+}
+
+impl Hash for Precedence<Version> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        Precedence(VersionRef::from(&self.0)).hash(hasher);
+    }
+}
+
+impl PartialEq for Precedence<VersionRef<'_>> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmp(rhs) == Ordering::Equal
+    }
+}
+
+impl Eq for Precedence<VersionRef<'_>> {}
+
+impl PartialOrd for Precedence<VersionRef<'_>> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl Ord for Precedence<VersionRef<'_>> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.0.cmp_precedence(&rhs.0)
+    }
+}
+
+impl Hash for Precedence<VersionRef<'_>> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        let version = &self.0;
+        version.major.hash(hasher);
+        version.minor.hash(hasher);
+        version.patch.hash(hasher);
+        version.pre.hash(hasher);
     }
 }
 
@@ -158,3 +146,175 @@ impl FromIterator<Comparator> for VersionReq {
         VersionReq { comparators }
     }
 }
+
+impl<const N: usize> PartialEq for FixedVersionReq<N> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.comparators() == rhs.comparators()
+    }
+}
+
+impl<const N: usize> Eq for FixedVersionReq<N> {}
+
+impl<const N: usize> Hash for FixedVersionReq<N> {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.comparators().hash(hasher);
+    }
+}
+
+pub(crate) fn cmp_prerelease(lhs: &str, rhs: &str) -> Ordering {
+    // Spec: "Identifiers with letters or hyphens are compared lexically in
+    // ASCII sort order."
+    cmp_prerelease_with(lhs, rhs, Ord::cmp)
+}
+
+// Like cmp_prerelease, except that alphanumeric identifiers are compared by
+// their runs of digits and non-digits, so that alpha9 < alpha10. Identifiers
+// that differ only in leading zeros of a run fall back to ASCII order, so the
+// ordering is still total.
+pub(crate) fn cmp_prerelease_natural(lhs: &str, rhs: &str) -> Ordering {
+    cmp_prerelease_with(lhs, rhs, |lhs, rhs| {
+        cmp_natural(lhs, rhs).then_with(|| Ord::cmp(lhs, rhs))
+    })
+}
+
+fn cmp_prerelease_with(
+    lhs: &str,
+    rhs: &str,
+    alphanumeric: impl Fn(&str, &str) -> Ordering,
+) -> Ordering {
+    match (lhs.is_empty(), rhs.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        // A real release compares greater than prerelease.
+        (true, false) => return Ordering::Greater,
+        // Prerelease compares less than the real release.
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    let lhs = lhs.split('.');
+    let mut rhs = rhs.split('.');
+
+    for lhs in lhs {
+        let Some(rhs) = rhs.next() else {
+            // Spec: "A larger set of pre-release fields has a higher
+            // precedence than a smaller set, if all of the preceding
+            // identifiers are equal."
+            return Ordering::Greater;
+        };
+
+        let string_cmp = || Ord::cmp(lhs, rhs);
+        let is_ascii_digit = |b: u8| b.is_ascii_digit();
+        let ordering = match (
+            lhs.bytes().all(is_ascii_digit),
+            rhs.bytes().all(is_ascii_digit),
+        ) {
+            // Respect numeric ordering, for example 99 < 100. Spec says:
+            // "Identifiers consisting of only digits are compared
+            // numerically."
+            (true, true) => Ord::cmp(&lhs.len(), &rhs.len()).then_with(string_cmp),
+            // Spec: "Numeric identifiers always have lower precedence than
+            // non-numeric identifiers."
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => alphanumeric(lhs, rhs),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    if rhs.next().is_none() {
+        Ordering::Equal
+    } else {
+        Ordering::Less
+    }
+}
+
+pub(crate) fn cmp_build_metadata(lhs: &str, rhs: &str) -> Ordering {
+    let lhs = lhs.split('.');
+    let mut rhs = rhs.split('.');
+
+    for lhs in lhs {
+        let Some(rhs) = rhs.next() else {
+            return Ordering::Greater;
+        };
+
+        let is_ascii_digit = |b: u8| b.is_ascii_digit();
+        let ordering = match (
+            lhs.bytes().all(is_ascii_digit),
+            rhs.bytes().all(is_ascii_digit),
+        ) {
+            (true, true) => {
+                // 0 < 00 < 1 < 01 < 001 < 2 < 02 < 002 < 10
+                let lhval = lhs.trim_start_matches('0');
+                let rhval = rhs.trim_start_matches('0');
+                Ord::cmp(&lhval.len(), &rhval.len())
+                    .then_with(|| Ord::cmp(lhval, rhval))
+                    .then_with(|| Ord::cmp(&lhs.len(), &rhs.len()))
+            }
+            (true, false) => return Ordering::Less,
+            (false, true) => return Ordering::Greater,
+            (false, false) => Ord::cmp(lhs, rhs),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    if rhs.next().is_none() {
+        Ordering::Equal
+    } else {
+        Ordering::Less
+    }
+}
+
+// Compare runs of digits numerically and runs of non-digits in ASCII order, with
+// a run of digits less than a run of non-digits, consistent with how whole
+// identifiers are ordered.
+fn cmp_natural(lhs: &str, rhs: &str) -> Ordering {
+    let mut lhs = lhs.as_bytes();
+    let mut rhs = rhs.as_bytes();
+
+    loop {
+        let (Some(l), Some(r)) = (lhs.first(), rhs.first()) else {
+            return Ord::cmp(&lhs.is_empty(), &rhs.is_empty()).reverse();
+        };
+
+        let (l_digits, r_digits) = (l.is_ascii_digit(), r.is_ascii_digit());
+        let l_len = run_len(lhs, l_digits);
+        let r_len = run_len(rhs, r_digits);
+        let (l_run, r_run) = (&lhs[..l_len], &rhs[..r_len]);
+
+        let ordering = match (l_digits, r_digits) {
+            (true, true) => {
+                let lhval = trim_leading_zeros(l_run);
+                let rhval = trim_leading_zeros(r_run);
+                Ord::cmp(&lhval.len(), &rhval.len()).then_with(|| Ord::cmp(lhval, rhval))
+            }
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => Ord::cmp(l_run, r_run),
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+
+        lhs = &lhs[l_len..];
+        rhs = &rhs[r_len..];
+    }
+}
+
+fn run_len(bytes: &[u8], digits: bool) -> usize {
+    bytes
+        .iter()
+        .position(|b| b.is_ascii_digit() != digits)
+        .unwrap_or(bytes.len())
+}
+
+fn trim_leading_zeros(digits: &[u8]) -> &[u8] {
+    let zeros = digits.iter().take_while(|&&b| b == b'0').count();
+    &digits[zeros..]
+}
@@ -0,0 +1,269 @@
+use crate::compiled::CompiledReq;
+use crate::interval;
+use crate::{Version, VersionReq};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Bound;
+use core::slice;
+
+// Most entries inserted since the tree was last built, which every query
+// checks one by one.
+const MAX_PENDING: usize = 32;
+
+/// Collection of version requirements, searchable by the versions they match.
+///
+/// Each requirement is stored under a key of type `K`. Given a version,
+/// [`matching`][ReqIndex::matching] finds the keys of all requirements that
+/// match the version, without evaluating every requirement in the index. The
+/// results are identical to calling [`VersionReq::matches`] on each one.
+///
+/// Internally this is an interval tree over the lower and upper bound of each
+/// requirement, as computed by [`VersionReq::compile`], plus a short unsorted
+/// list of at most 32 recently inserted requirements. Finding the requirements
+/// that match a version takes time logarithmic in the size of the index for
+/// each requirement whose bounds contain the version. Nearly all of those are
+/// matches; the exceptions are pre-release versions, which most requirements
+/// exclude even within their bounds, and versions that fall in a gap between
+/// the comparators of a requirement.
+///
+/// # Example
+///
+/// ```
+/// use semver::{ReqIndex, Version, VersionReq};
+///
+/// let mut index = ReqIndex::new();
+/// index.insert("serde_json", &VersionReq::parse("^1.0.100").unwrap());
+/// index.insert("toml", &VersionReq::parse("1.0.50").unwrap());
+/// index.insert("old", &VersionReq::parse("<1.0.0").unwrap());
+///
+/// let version = Version::parse("1.0.108").unwrap();
+/// let mut dependents: Vec<_> = index.matching(&version).collect();
+/// dependents.sort();
+/// assert_eq!(dependents, [&"serde_json", &"toml"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReqIndex<K> {
+    // Sorted by lower bound, followed by `pending` unsorted entries that have
+    // been inserted since the tree was last built.
+    entries: Vec<Entry<K>>,
+    // Segment tree over the sorted entries, holding for each node the index of
+    // the entry with the highest upper bound within that node's span.
+    tree: Vec<usize>,
+    pending: usize,
+}
+
+#[derive(Clone, Debug)]
+struct Entry<K> {
+    key: K,
+    req: CompiledReq,
+}
+
+impl<K> ReqIndex<K> {
+    /// Create an empty index.
+    pub const fn new() -> Self {
+        ReqIndex {
+            entries: Vec::new(),
+            tree: Vec::new(),
+            pending: 0,
+        }
+    }
+
+    /// Add a version requirement to the index, stored under the given key.
+    ///
+    /// Keys need not be unique; inserting the same key twice results in it
+    /// being reported by `matching` once for each time it was inserted with a
+    /// requirement that matches.
+    ///
+    /// Requirements are first added to an unsorted tail of at most 32 entries,
+    /// which queries scan linearly. When the tail is full, the tree is rebuilt
+    /// in time linear in the size of the index. To fill a large index, prefer
+    /// [`extend`][Extend::extend] or [`collect`][Iterator::collect], which
+    /// rebuild only once.
+    pub fn insert(&mut self, key: K, req: &VersionReq) {
+        self.push(key, req);
+        if self.pending > MAX_PENDING {
+            self.rebuild();
+        }
+    }
+
+    /// Iterate over the keys of all requirements in the index that match the
+    /// given version, in no particular order.
+    ///
+    /// The index is searched lazily as the iterator is advanced.
+    pub fn matching<'a>(&'a self, version: &'a Version) -> impl Iterator<Item = &'a K> + 'a {
+        let sorted = self.entries.len() - self.pending;
+        let (sorted_entries, pending_entries) = self.entries.split_at(sorted);
+
+        let mut stack = Vec::new();
+        let mut end = 0;
+        if sorted > 0 {
+            // Entries whose lower bound admits the version form a prefix.
+            end = sorted_entries.partition_point(|entry| entry.is_above_lower(version));
+            stack.push((1, 0, self.tree.len() / 2));
+        }
+
+        Matching {
+            index: self,
+            version,
+            stack,
+            end,
+            pending: pending_entries.iter(),
+        }
+    }
+
+    /// Number of requirements in the index.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn push(&mut self, key: K, req: &VersionReq) {
+        let req = req.compile();
+        self.entries.push(Entry { key, req });
+        self.pending += 1;
+    }
+
+    fn rebuild(&mut self) {
+        // The stable sort finds the sorted prefix and the pending tail as two
+        // runs, and merges them in linear time after sorting the tail.
+        self.entries
+            .sort_by(|a, b| cmp_lower(&a.req.lower, &b.req.lower));
+        self.pending = 0;
+
+        let leaves = self.entries.len().next_power_of_two();
+        self.tree.clear();
+        self.tree.resize(2 * leaves, usize::MAX);
+        for i in 0..self.entries.len() {
+            self.tree[leaves + i] = i;
+        }
+        for node in (1..leaves).rev() {
+            let left = self.tree[2 * node];
+            let right = self.tree[2 * node + 1];
+            self.tree[node] = match (left, right) {
+                (usize::MAX, _) => right,
+                (_, usize::MAX) => left,
+                _ => {
+                    let left_upper = &self.entries[left].req.upper;
+                    let right_upper = &self.entries[right].req.upper;
+                    match cmp_upper(left_upper, right_upper) {
+                        Ordering::Less => right,
+                        Ordering::Equal | Ordering::Greater => left,
+                    }
+                }
+            };
+        }
+    }
+}
+
+// Depth-first search of the tree for entries whose bounds admit the version,
+// followed by a scan of the pending entries.
+struct Matching<'a, K> {
+    index: &'a ReqIndex<K>,
+    version: &'a Version,
+    // Nodes left to visit, with the span [lo, hi) of entries under each.
+    stack: Vec<(usize, usize, usize)>,
+    // Only the first `end` sorted entries have a lower bound that admits the
+    // version.
+    end: usize,
+    pending: slice::Iter<'a, Entry<K>>,
+}
+
+impl<'a, K> Iterator for Matching<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entries = &self.index.entries;
+        while let Some((node, lo, hi)) = self.stack.pop() {
+            let max = self.index.tree[node];
+            if lo >= self.end || max == usize::MAX || !entries[max].is_below_upper(self.version) {
+                continue;
+            }
+            if hi - lo == 1 {
+                let entry = &entries[lo];
+                if entry.req.matches(self.version) {
+                    return Some(&entry.key);
+                }
+                continue;
+            }
+            let mid = lo + (hi - lo) / 2;
+            self.stack.push((2 * node + 1, mid, hi));
+            self.stack.push((2 * node, lo, mid));
+        }
+        let version = self.version;
+        self.pending
+            .find(|entry| entry.req.matches(version))
+            .map(|entry| &entry.key)
+    }
+}
+
+impl<K> Entry<K> {
+    fn is_above_lower(&self, version: &Version) -> bool {
+        interval::is_above(&self.req.lower, version)
+    }
+
+    fn is_below_upper(&self, version: &Version) -> bool {
+        interval::is_below(&self.req.upper, version)
+    }
+}
+
+impl<K> Default for ReqIndex<K> {
+    fn default() -> Self {
+        ReqIndex::new()
+    }
+}
+
+impl<K> Extend<(K, VersionReq)> for ReqIndex<K> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (K, VersionReq)>,
+    {
+        for (key, req) in iter {
+            self.push(key, &req);
+        }
+        if self.pending > MAX_PENDING {
+            self.rebuild();
+        }
+    }
+}
+
+impl<K> FromIterator<(K, VersionReq)> for ReqIndex<K> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, VersionReq)>,
+    {
+        let mut index = ReqIndex::new();
+        index.extend(iter);
+        index
+    }
+}
+
+// Order lower bounds from least to most restrictive.
+fn cmp_lower(a: &Bound<Version>, b: &Bound<Version>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Less,
+        (_, Bound::Unbounded) => Ordering::Greater,
+        (Bound::Included(x) | Bound::Excluded(x), Bound::Included(y) | Bound::Excluded(y)) => x
+            .cmp_precedence(y)
+            .then_with(|| is_excluded(a).cmp(&is_excluded(b))),
+    }
+}
+
+// Order upper bounds from most to least restrictive.
+fn cmp_upper(a: &Bound<Version>, b: &Bound<Version>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Greater,
+        (_, Bound::Unbounded) => Ordering::Less,
+        (Bound::Included(x) | Bound::Excluded(x), Bound::Included(y) | Bound::Excluded(y)) => x
+            .cmp_precedence(y)
+            .then_with(|| is_excluded(b).cmp(&is_excluded(a))),
+    }
+}
+
+fn is_excluded(bound: &Bound<Version>) -> bool {
+    matches!(bound, Bound::Excluded(_))
+}
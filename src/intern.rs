@@ -0,0 +1,154 @@
+use crate::identifier::Identifier;
+use crate::{BuildMetadata, Prerelease, Version};
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt::{self, Debug};
+use std::mem;
+
+/// Pool of pre-release and build metadata strings shared by many versions.
+///
+/// Interning a version replaces its pre-release and build metadata with a
+/// reference-counted copy held by the interner, so that every version interned
+/// with the same interner and repeating the same string shares one allocation.
+/// Clones of interned versions share it too, and comparisons between them are
+/// as cheap as for short strings. Strings of 8 bytes or less are stored inline
+/// anyway and are returned unchanged.
+///
+/// The interner frees nothing while it is alive, so it grows with the number of
+/// distinct strings interned. Dropping it releases the pool: each string is
+/// freed once the last version using it is dropped, and versions interned
+/// earlier remain valid.
+///
+/// Interning happens after the fact: the version being interned already has an
+/// allocation of its own from parsing, which is freed when it is replaced.
+/// Interning right after parsing each version keeps at most one such
+/// allocation alive at a time.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Version, VersionInterner};
+///
+/// let snapshot = ["1.0.0-alpha.20240101", "2.3.0-alpha.20240101"];
+/// let mut interner = VersionInterner::new();
+/// let versions: Vec<Version> = snapshot
+///     .iter()
+///     .map(|v| interner.intern(Version::parse(v).unwrap()))
+///     .collect();
+///
+/// // Both pre-releases point to the same allocation.
+/// assert_eq!(
+///     versions[0].pre.as_str().as_ptr(),
+///     versions[1].pre.as_str().as_ptr(),
+/// );
+///
+/// // Interned versions outlive the interner.
+/// drop(interner);
+/// assert_eq!(versions[1].to_string(), "2.3.0-alpha.20240101");
+/// ```
+#[derive(Default)]
+pub struct VersionInterner {
+    pool: BTreeSet<Entry>,
+}
+
+// An identifier in the shared repr, ordered by its string so the pool can be
+// searched by str.
+struct Entry(Identifier);
+
+impl VersionInterner {
+    /// Create an empty interner.
+    pub const fn new() -> Self {
+        VersionInterner {
+            pool: BTreeSet::new(),
+        }
+    }
+
+    /// Intern the pre-release and build metadata of a version.
+    #[must_use]
+    pub fn intern(&mut self, version: Version) -> Version {
+        Version {
+            major: version.major,
+            minor: version.minor,
+            patch: version.patch,
+            pre: self.intern_prerelease(version.pre),
+            build: self.intern_build(version.build),
+        }
+    }
+
+    /// Intern a pre-release on its own.
+    #[must_use]
+    pub fn intern_prerelease(&mut self, pre: Prerelease) -> Prerelease {
+        Prerelease {
+            identifier: self.identifier(pre.identifier),
+        }
+    }
+
+    /// Intern build metadata on its own. The pool is shared with pre-releases,
+    /// so a string that occurs as both is stored once.
+    #[must_use]
+    pub fn intern_build(&mut self, build: BuildMetadata) -> BuildMetadata {
+        BuildMetadata {
+            identifier: self.identifier(build.identifier),
+        }
+    }
+
+    /// Number of distinct strings held by the interner.
+    pub fn len(&self) -> usize {
+        self.pool.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pool.is_empty()
+    }
+
+    fn identifier(&mut self, identifier: Identifier) -> Identifier {
+        // Empty and inline identifiers do not allocate, so there is nothing to
+        // share.
+        if identifier.as_str().len() <= mem::size_of::<Identifier>() {
+            return identifier;
+        }
+
+        if let Some(entry) = self.pool.get(identifier.as_str()) {
+            return entry.0.clone();
+        }
+        let shared = identifier.into_shared();
+        self.pool.insert(Entry(shared.clone()));
+        shared
+    }
+}
+
+impl Debug for VersionInterner {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter
+            .debug_set()
+            .entries(self.pool.iter().map(|entry| entry.0.as_str()))
+            .finish()
+    }
+}
+
+impl Borrow<str> for Entry {
+    fn borrow(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.as_str().cmp(other.0.as_str())
+    }
+}
@@ -0,0 +1,225 @@
+// Every comparator matches a contiguous range of versions in precedence order,
+// before applying the pre-release rule from matches_req. The one wrinkle is
+// that some operators written with a partial version, such as `>=1.2` or `~1`,
+// match only the releases and none of the pre-releases within the range of
+// that partial version:
+//
+//     >=1.2     matches 1.2.0, 1.2.7, 1.3.0-alpha, 2.0.0
+//               but not 1.2.7-alpha
+//
+// So each comparator is represented as a pair of bounds plus an optional
+// (major, minor) prefix inside of which pre-releases are not matched.
+//
+// Bounds are expressed as versions without build metadata. The lowest version
+// that has a given major.minor.patch is the one with pre-release `0`, which
+// is how exclusive upper bounds like the `<2.0.0` implied by `^1.2.3` are
+// written: `<2.0.0-0` so that 2.0.0-alpha is excluded too.
+
+use crate::identifier::Identifier;
+use crate::{BuildMetadata, Comparator, Op, Prerelease, Version};
+use core::cmp::Ordering;
+use core::ops::Bound;
+
+pub(crate) struct Interval {
+    pub lower: Bound<Version>,
+    pub upper: Bound<Version>,
+    pub release_only: Option<(u64, Option<u64>)>,
+}
+
+impl Interval {
+    pub fn of(cmp: &Comparator) -> Self {
+        let release_only = Some((cmp.major, cmp.minor));
+        let full = match (cmp.minor, cmp.patch) {
+            (Some(minor), Some(patch)) => Some(exact(cmp, minor, patch)),
+            _ => None,
+        };
+        let (lower, upper, release_only) = match (cmp.op, full) {
+            (Op::Exact | Op::Wildcard, Some(version)) => (
+                Bound::Included(version.clone()),
+                Bound::Included(version),
+                None,
+            ),
+            (Op::Exact | Op::Wildcard | Op::Tilde, None) => {
+                let lower = Bound::Included(floor_of(cmp.major, cmp.minor));
+                (lower, after(cmp.major, cmp.minor), release_only)
+            }
+            (Op::Greater, Some(version)) => (Bound::Excluded(version), Bound::Unbounded, None),
+            (Op::Greater, None) => match after(cmp.major, cmp.minor) {
+                Bound::Excluded(floor) => (Bound::Included(floor), Bound::Unbounded, None),
+                _ => return Interval::empty(),
+            },
+            (Op::GreaterEq, Some(version)) => (Bound::Included(version), Bound::Unbounded, None),
+            (Op::GreaterEq, None) => {
+                let lower = Bound::Included(floor_of(cmp.major, cmp.minor));
+                (lower, Bound::Unbounded, release_only)
+            }
+            (Op::Less, Some(version)) => (Bound::Unbounded, Bound::Excluded(version), None),
+            (Op::Less, None) => {
+                let upper = Bound::Excluded(floor_of(cmp.major, cmp.minor));
+                (Bound::Unbounded, upper, None)
+            }
+            (Op::LessEq, Some(version)) => (Bound::Unbounded, Bound::Included(version), None),
+            (Op::LessEq, None) => (Bound::Unbounded, after(cmp.major, cmp.minor), release_only),
+            (Op::Tilde, Some(version)) => {
+                let upper = after(cmp.major, cmp.minor);
+                (Bound::Included(version), upper, None)
+            }
+            (Op::Caret, full) => {
+                let lower = Bound::Included(match full {
+                    Some(version) => version,
+                    None => floor_of(cmp.major, cmp.minor),
+                });
+                let upper = match (cmp.major, cmp.minor, cmp.patch) {
+                    (0, Some(0), Some(patch)) => match patch.checked_add(1) {
+                        Some(next) => Bound::Excluded(floor(0, 0, next)),
+                        None => after(0, Some(0)),
+                    },
+                    (0, minor, _) => after(0, minor),
+                    (major, _, _) => after(major, None),
+                };
+                (lower, upper, None)
+            }
+        };
+        Interval {
+            lower,
+            upper,
+            release_only,
+        }
+    }
+
+    pub fn unbounded() -> Self {
+        Interval {
+            lower: Bound::Unbounded,
+            upper: Bound::Unbounded,
+            release_only: None,
+        }
+    }
+
+    fn empty() -> Self {
+        Interval {
+            lower: Bound::Included(Version::new(0, 0, 0)),
+            upper: Bound::Excluded(floor(0, 0, 0)),
+            release_only: None,
+        }
+    }
+
+    pub fn is_above_lower(&self, ver: &Version) -> bool {
+        is_above(&self.lower, ver)
+    }
+
+    pub fn is_below_upper(&self, ver: &Version) -> bool {
+        is_below(&self.upper, ver)
+    }
+
+    // Narrow self to the versions also within other's bounds. Which
+    // pre-releases are excluded by either is not tracked.
+    pub fn intersect(&mut self, other: Interval) {
+        if is_tighter_lower(&other.lower, &self.lower) {
+            self.lower = other.lower;
+        }
+        if is_tighter_upper(&other.upper, &self.upper) {
+            self.upper = other.upper;
+        }
+        self.release_only = None;
+    }
+
+    pub fn excludes_pre(&self, ver: &Version) -> bool {
+        match self.release_only {
+            Some((major, minor)) => {
+                !ver.pre.is_empty()
+                    && ver.major == major
+                    && minor.map_or(true, |minor| ver.minor == minor)
+            }
+            None => false,
+        }
+    }
+}
+
+pub(crate) fn is_above(lower: &Bound<Version>, ver: &Version) -> bool {
+    match lower {
+        Bound::Unbounded => true,
+        Bound::Included(lower) => ver.cmp_precedence(lower) != Ordering::Less,
+        Bound::Excluded(lower) => ver.cmp_precedence(lower) == Ordering::Greater,
+    }
+}
+
+pub(crate) fn is_below(upper: &Bound<Version>, ver: &Version) -> bool {
+    match upper {
+        Bound::Unbounded => true,
+        Bound::Included(upper) => ver.cmp_precedence(upper) != Ordering::Greater,
+        Bound::Excluded(upper) => ver.cmp_precedence(upper) == Ordering::Less,
+    }
+}
+
+fn is_tighter_lower(a: &Bound<Version>, b: &Bound<Version>) -> bool {
+    match (a, b) {
+        (Bound::Unbounded, _) => false,
+        (_, Bound::Unbounded) => true,
+        (Bound::Included(x) | Bound::Excluded(x), Bound::Included(y) | Bound::Excluded(y)) => {
+            match x.cmp_precedence(y) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => matches!((a, b), (Bound::Excluded(_), Bound::Included(_))),
+            }
+        }
+    }
+}
+
+fn is_tighter_upper(a: &Bound<Version>, b: &Bound<Version>) -> bool {
+    match (a, b) {
+        (Bound::Unbounded, _) => false,
+        (_, Bound::Unbounded) => true,
+        (Bound::Included(x) | Bound::Excluded(x), Bound::Included(y) | Bound::Excluded(y)) => {
+            match x.cmp_precedence(y) {
+                Ordering::Less => true,
+                Ordering::Greater => false,
+                Ordering::Equal => matches!((a, b), (Bound::Excluded(_), Bound::Included(_))),
+            }
+        }
+    }
+}
+
+// The lowest possible pre-release, lower than every other pre-release.
+fn lowest_pre() -> Prerelease {
+    // SAFETY: "0" is ASCII and contains no \0 byte.
+    let identifier = unsafe { Identifier::new_unchecked("0") };
+    Prerelease { identifier }
+}
+
+// The lowest version having the given major.minor.patch.
+pub(crate) fn floor(major: u64, minor: u64, patch: u64) -> Version {
+    Version {
+        major,
+        minor,
+        patch,
+        pre: lowest_pre(),
+        build: BuildMetadata::EMPTY,
+    }
+}
+
+fn floor_of(major: u64, minor: Option<u64>) -> Version {
+    floor(major, minor.unwrap_or(0), 0)
+}
+
+fn exact(cmp: &Comparator, minor: u64, patch: u64) -> Version {
+    Version {
+        major: cmp.major,
+        minor,
+        patch,
+        pre: cmp.pre.clone(),
+        build: BuildMetadata::EMPTY,
+    }
+}
+
+// Exclusive upper bound of the versions that start with a partial version.
+fn after(major: u64, minor: Option<u64>) -> Bound<Version> {
+    if let Some(minor) = minor {
+        if let Some(next) = minor.checked_add(1) {
+            return Bound::Excluded(floor(major, next, 0));
+        }
+    }
+    match major.checked_add(1) {
+        Some(next) => Bound::Excluded(floor(next, 0, 0)),
+        None => Bound::Unbounded,
+    }
+}
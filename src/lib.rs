@@ -59,6 +59,17 @@
 //! Dependencies]* chapter of the Cargo reference.
 //!
 //! [Specifying Dependencies]: https://doc.rust-lang.org/cargo/reference/specifying-dependencies.html
+//!
+//! <br><br>
+//!
+//! # Parsing without allocating
+//!
+//! Pre-release and build metadata of up to 8 bytes are stored inline, and only
+//! longer ones are allocated on the heap, as are the comparators of a
+//! [`VersionReq`]. For code that must not allocate, [`Version::parse_inline`]
+//! and [`FixedVersionReq`] accept the same syntax but fail with an error
+//! wherever the other parsers would allocate, while [`VersionRef`] and
+//! [`ComparatorRef`] borrow from the input instead of copying it.
 
 #![doc(html_root_url = "https://docs.rs/semver/1.0.27")]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -88,22 +99,80 @@
 
 extern crate alloc;
 
+#[cfg(feature = "arbitrary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "arbitrary")))]
+pub mod arbitrary;
+mod borrowed;
+mod compiled;
+mod describe;
 mod display;
+mod embedded;
 mod error;
 mod eval;
+mod explain;
+pub mod firmware;
+mod fixed;
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+mod generate;
+mod git;
 mod identifier;
 mod impls;
+mod index;
+#[cfg(feature = "std")]
+mod intern;
+mod interval;
+mod macros;
+mod options;
+mod packed;
 mod parse;
+mod precedence;
+mod set;
+mod sortable;
+mod strict;
+mod tag;
+
+#[cfg(feature = "proptest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "proptest")))]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "quickcheck")))]
+pub mod quickcheck;
+#[cfg(feature = "schemars")]
+mod schemars;
 
 #[cfg(feature = "serde")]
-mod serde;
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
 
 use crate::identifier::Identifier;
+use alloc::string::String;
 use alloc::vec::Vec;
 use core::cmp::Ordering;
 use core::str::FromStr;
 
+pub use crate::borrowed::{ComparatorRef, VersionRef};
+pub use crate::compiled::CompiledReq;
+pub use crate::embedded::cmp_embedded;
+pub use crate::explain::{ComparatorExplanation, Explanation, PrereleaseRule, Violation};
+pub use crate::fixed::FixedVersionReq;
+pub use crate::git::DescribeFormat;
+pub use crate::index::ReqIndex;
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::intern::VersionInterner;
+pub use crate::options::ParseOptions;
+pub use crate::packed::{PackedVersion, PackedVersion128};
 pub use crate::parse::Error;
+pub use crate::precedence::{dedup_by_precedence, Precedence};
+pub use crate::set::VersionSet;
+pub use crate::strict::{StrictError, StrictViolation};
+pub use crate::tag::TagScheme;
+
+// Not public API. Used by generated code.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::version;
+}
 
 /// **SemVer version** as defined by <https://semver.org>.
 ///
@@ -256,6 +325,45 @@ pub enum Op {
     Wildcard,
 }
 
+/// Where a version lies relative to the range of versions matched by a
+/// [`VersionReq`]. Returned by [`VersionReq::position`].
+///
+/// # Example
+///
+/// ```
+/// use semver::{RangePosition, Version, VersionReq};
+///
+/// let req = VersionReq::parse(">=1.2.3, <1.8.0").unwrap();
+///
+/// let position = |v| req.position(&Version::parse(v).unwrap());
+/// assert_eq!(position("1.0.0"), RangePosition::Below);
+/// assert_eq!(position("1.5.0"), RangePosition::Within);
+/// assert_eq!(position("1.5.0-rc.1"), RangePosition::PrereleaseExcluded);
+/// assert_eq!(position("1.8.0"), RangePosition::Above);
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+#[non_exhaustive]
+pub enum RangePosition {
+    /// The version is lower than every version matched by the requirement. At
+    /// least one comparator has a lower bound above the version, and no
+    /// comparator has an upper bound below it.
+    Below,
+    /// The version matches the requirement.
+    Within,
+    /// The version is higher than every version matched by the requirement.
+    /// At least one comparator has an upper bound below the version, and no
+    /// comparator has a lower bound above it.
+    Above,
+    /// The version is above the upper bound of one comparator and below the
+    /// lower bound of another, as in 0.3.0 against `<=0.2.0, >=0.5.0`. This
+    /// only happens for requirements that match no version at all.
+    Gap,
+    /// The version is within the bounds of every comparator, but it is a
+    /// pre-release and does not match because of the rules around
+    /// pre-releases. See [`VersionReq::STAR`] for those rules.
+    PrereleaseExcluded,
+}
+
 /// Optional pre-release identifier on a version string. This comes after `-` in
 /// a SemVer version, like `1.0.0-alpha.1`
 ///
@@ -423,6 +531,96 @@ impl Version {
         Version::from_str(text)
     }
 
+    /// Create `Version` by parsing from string representation, rejecting
+    /// input that exceeds the given limits. See [`ParseOptions`].
+    pub fn parse_with(text: &str, options: &ParseOptions) -> Result<Self, Error> {
+        options::version(text, options)
+    }
+
+    /// Create `Version` by parsing from string representation, without
+    /// allocating.
+    ///
+    /// Accepts the same syntax as [`Version::parse`], but fails with an error
+    /// instead of allocating if the pre-release or build metadata is longer
+    /// than the 8 bytes that are stored inline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let version = Version::parse_inline("1.2.3-rc.1+a1b2c3").unwrap();
+    /// assert_eq!(version, Version::parse("1.2.3-rc.1+a1b2c3").unwrap());
+    ///
+    /// assert!(Version::parse_inline("1.2.3-alpha.1.2.3").is_err());
+    /// ```
+    pub fn parse_inline(text: &str) -> Result<Self, Error> {
+        parse::version(text)?.to_inline_version()
+    }
+
+    /// Create `Version` by parsing from string representation, checking it
+    /// against exactly the grammar of the semver.org specification.
+    ///
+    /// [`Version::parse`] accepts the same syntax, but stops at the first
+    /// problem. This reports every way in which the input departs from the
+    /// grammar, for example both leading zeros in `01.02.3`. It also reports
+    /// numbers that the grammar allows but that exceed `u64::MAX`; see
+    /// [`StrictViolation::exceeds_u64`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let error = Version::parse_strict("1.02.3-beta.01+build_7").unwrap_err();
+    /// assert_eq!(error.violations().len(), 3);
+    /// assert_eq!(error.violations()[1].span(), 12..14);
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "3 violations of the semver.org grammar: \
+    ///      invalid leading zero in minor version number; \
+    ///      invalid leading zero in pre-release identifier; \
+    ///      unexpected character '_' in build metadata",
+    /// );
+    /// ```
+    pub fn parse_strict(text: &str) -> Result<Self, StrictError> {
+        strict::parse(text)
+    }
+
+    /// Create a development version from the output of `git describe --tags`.
+    ///
+    /// Commits after a release tag become a pre-release of the next patch
+    /// version, such that `v1.2.3-14-gabc1234-dirty` is
+    /// `1.2.4-dev.14+gabc1234.dirty`. It sorts after `1.2.3` and before
+    /// `1.2.4`, and among the other development versions between them by the
+    /// number of commits. After a pre-release tag, the development version is
+    /// a longer pre-release of the same version, such that `v2.0.0-rc.1-3-g1f2e`
+    /// is `2.0.0-rc.1.dev.3+g1f2e`, which sorts before `2.0.0-rc.2`.
+    ///
+    /// The output for a tagged commit without uncommitted changes is the
+    /// version in the tag. The tag may be preceded by `v`. For other tags and
+    /// templates, see [`Version::from_git_describe_with`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let tagged = Version::from_git_describe("v1.2.3").unwrap();
+    /// let nightly = Version::from_git_describe("v1.2.3-14-gabc1234-dirty").unwrap();
+    /// assert_eq!(nightly.to_string(), "1.2.4-dev.14+gabc1234.dirty");
+    /// assert!(tagged < nightly && nightly < Version::new(1, 2, 4));
+    /// ```
+    pub fn from_git_describe(text: &str) -> Result<Self, Error> {
+        git::from_describe(text, &DescribeFormat::new())
+    }
+
+    /// Create a development version from the output of `git describe --tags`
+    /// with the given tag scheme and templates. See [`DescribeFormat`].
+    pub fn from_git_describe_with(text: &str, format: &DescribeFormat) -> Result<Self, Error> {
+        git::from_describe(text, format)
+    }
+
     /// Compare the major, minor, patch, and pre-release value of two versions,
     /// disregarding build metadata. Versions that differ only in build metadata
     /// are considered equal. This comparison is what the SemVer spec refers to
@@ -469,6 +667,119 @@ impl Version {
             &(other.major, other.minor, other.patch, &other.pre),
         )
     }
+
+    /// Compare versions like [`Ord`], except that alphanumeric pre-release
+    /// identifiers are compared in natural order.
+    ///
+    /// The spec compares identifiers containing letters in ASCII order, so
+    /// `1.0.0-alpha10` sorts before `1.0.0-alpha9`. Here each such identifier
+    /// is instead split into runs of digits and non-digits, and the runs of
+    /// digits are compared numerically. Numeric identifiers, the major, minor
+    /// and patch numbers, and build metadata are compared as usual.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let mut versions = ["1.0.0-alpha10", "1.0.0-alpha9", "1.0.0-beta2", "1.0.0"]
+    ///     .map(|v| Version::parse(v).unwrap());
+    ///
+    /// versions.sort();
+    /// assert_eq!(versions[0].pre.as_str(), "alpha10");
+    ///
+    /// versions.sort_by(Version::cmp_natural);
+    /// assert_eq!(
+    ///     versions.map(|v| v.to_string()),
+    ///     ["1.0.0-alpha9", "1.0.0-alpha10", "1.0.0-beta2", "1.0.0"],
+    /// );
+    /// ```
+    pub fn cmp_natural(&self, other: &Self) -> Ordering {
+        Ord::cmp(
+            &(self.major, self.minor, self.patch),
+            &(other.major, other.minor, other.patch),
+        )
+        .then_with(|| impls::cmp_prerelease_natural(&self.pre, &other.pre))
+        .then_with(|| Ord::cmp(&self.build, &other.build))
+    }
+
+    /// Encode this version as bytes whose lexicographic order is the same as
+    /// the order of versions.
+    ///
+    /// For any two versions `a` and `b`, `a.cmp(&b)` is equal to
+    /// `a.to_sortable_bytes().cmp(&b.to_sortable_bytes())`. This makes the
+    /// encoding suitable as a key in a key-value store or database index that
+    /// orders keys by their bytes, so that range scans return versions in
+    /// version order. The original version is recovered by
+    /// [`Version::from_sortable_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let a = Version::parse("1.9.0").unwrap();
+    /// let b = Version::parse("1.10.0-alpha").unwrap();
+    /// let c = Version::parse("1.10.0").unwrap();
+    ///
+    /// // Unlike the string representation.
+    /// assert!(a.to_string() > b.to_string());
+    ///
+    /// assert!(a.to_sortable_bytes() < b.to_sortable_bytes());
+    /// assert!(b.to_sortable_bytes() < c.to_sortable_bytes());
+    /// ```
+    pub fn to_sortable_bytes(&self) -> Vec<u8> {
+        sortable::encode(self, true)
+    }
+
+    /// Encode the major, minor, patch, and pre-release of this version as bytes
+    /// whose lexicographic order is the same as the order of precedence.
+    ///
+    /// This is [`Version::to_sortable_bytes`] without the build metadata.
+    /// Versions that differ only in build metadata produce identical bytes, in
+    /// agreement with [`Version::cmp_precedence`]. Decoding the bytes using
+    /// [`Version::from_sortable_bytes`] produces a version with empty build
+    /// metadata.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let a = Version::parse("1.20.0+c144a98").unwrap();
+    /// let b = Version::parse("1.20.0+bc17664").unwrap();
+    ///
+    /// assert_ne!(a.to_sortable_bytes(), b.to_sortable_bytes());
+    /// assert_eq!(a.to_precedence_bytes(), b.to_precedence_bytes());
+    /// ```
+    pub fn to_precedence_bytes(&self) -> Vec<u8> {
+        sortable::encode(self, false)
+    }
+
+    /// Decode a version from the output of [`Version::to_sortable_bytes`] or
+    /// [`Version::to_precedence_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bytes are not exactly the encoding of some
+    /// version.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::Version;
+    ///
+    /// let version = Version::parse("1.0.0-rc.1+build.5").unwrap();
+    /// let bytes = version.to_sortable_bytes();
+    /// assert_eq!(Version::from_sortable_bytes(&bytes).unwrap(), version);
+    ///
+    /// let bytes = version.to_precedence_bytes();
+    /// let decoded = Version::from_sortable_bytes(&bytes).unwrap();
+    /// assert_eq!(decoded, Version::parse("1.0.0-rc.1").unwrap());
+    /// ```
+    pub fn from_sortable_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        sortable::decode(bytes)
+    }
 }
 
 impl VersionReq {
@@ -508,10 +819,91 @@ impl VersionReq {
         VersionReq::from_str(text)
     }
 
+    /// Create `VersionReq` by parsing from string representation, rejecting
+    /// input that exceeds the given limits. See [`ParseOptions`].
+    pub fn parse_with(text: &str, options: &ParseOptions) -> Result<Self, Error> {
+        options::version_req(text, options)
+    }
+
     /// Evaluate whether the given `Version` satisfies the version requirement
     /// described by `self`.
     pub fn matches(&self, version: &Version) -> bool {
-        eval::matches_req(self, version)
+        eval::matches_req(&self.comparators, &VersionRef::from(version))
+    }
+
+    /// Evaluate whether the given borrowed version satisfies the version
+    /// requirement described by `self`.
+    ///
+    /// This is the same as [`VersionReq::matches`] but for a [`VersionRef`].
+    pub fn matches_ref(&self, version: &VersionRef) -> bool {
+        eval::matches_req(&self.comparators, version)
+    }
+
+    /// Explain whether and why the given `Version` satisfies the version
+    /// requirement described by `self`.
+    ///
+    /// The report lists the outcome of each comparator, including which bound
+    /// the version falls outside of, as well as the outcome of the pre-release
+    /// rule described under [`VersionReq::STAR`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{PrereleaseRule, Version, VersionReq};
+    ///
+    /// let req = VersionReq::parse("^1.2.3").unwrap();
+    /// let version = Version::parse("1.4.0-rc.1").unwrap();
+    ///
+    /// let explanation = req.explain(&version);
+    /// assert!(!explanation.matches());
+    /// assert!(matches!(explanation.prerelease, PrereleaseRule::Excluded));
+    /// assert!(explanation.is_excluded_only_by_prerelease_rule());
+    /// ```
+    pub fn explain<'a>(&'a self, version: &'a Version) -> Explanation<'a> {
+        explain::explain(self, version)
+    }
+
+    /// Determine whether the given `Version` lies below, within, or above the
+    /// range of versions matched by `self`.
+    ///
+    /// A version that is within the bounds of the requirement but does not
+    /// match it because it is a pre-release is reported separately as
+    /// [`RangePosition::PrereleaseExcluded`], so that it is not mistaken for
+    /// an outdated or a too new version.
+    pub fn position(&self, version: &Version) -> RangePosition {
+        eval::position(self, version)
+    }
+
+    /// Describe in English which versions are matched by `self`, following
+    /// the semantics of each [`Op`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::VersionReq;
+    ///
+    /// let req = VersionReq::parse("^0.2.3").unwrap();
+    /// assert_eq!(
+    ///     req.describe(),
+    ///     "any 0.2.x version at or above 0.2.3 (0.x caret only allows patch updates)",
+    /// );
+    ///
+    /// let req = VersionReq::parse(">=1.2, <1.8").unwrap();
+    /// assert_eq!(
+    ///     req.describe(),
+    ///     "all of: any version at or above 1.2.0; any version below 1.8.0",
+    /// );
+    /// ```
+    pub fn describe(&self) -> String {
+        describe::describe_req(self)
+    }
+
+    /// Precompute a representation of `self` that is faster to evaluate
+    /// against a large number of versions.
+    ///
+    /// See [`CompiledReq`].
+    pub fn compile(&self) -> CompiledReq {
+        compiled::compile(self)
     }
 }
 
@@ -527,8 +919,20 @@ impl Comparator {
         Comparator::from_str(text)
     }
 
+    /// Create `Comparator` by parsing from string representation, rejecting
+    /// input that exceeds the given limits. See [`ParseOptions`].
+    pub fn parse_with(text: &str, options: &ParseOptions) -> Result<Self, Error> {
+        options::comparator(text, options)
+    }
+
     pub fn matches(&self, version: &Version) -> bool {
-        eval::matches_comparator(self, version)
+        eval::matches_comparator(&ComparatorRef::from(self), &VersionRef::from(version))
+    }
+
+    /// Describe in English which versions are matched by `self`. See
+    /// [`VersionReq::describe`].
+    pub fn describe(&self) -> String {
+        describe::describe_comparator(self)
     }
 }
 
@@ -0,0 +1,133 @@
+// The version! macro, which parses its argument during constant evaluation
+// with the same const fns that back Version::parse. A parse error becomes a
+// panic during constant evaluation, which rustc reports as a compile error at
+// the macro call site. Formatting is not available there, so the message
+// cannot include the offending character the way Error's Display does.
+
+use crate::error::{ErrorKind, Position};
+use crate::identifier::Identifier;
+use crate::parse::{self, Error};
+use crate::{BuildMetadata, Prerelease, Version};
+use core::mem;
+
+/// Construct a [`Version`] from a string, checked at compile time.
+///
+/// The string is parsed during constant evaluation, so a malformed version is
+/// a compile error rather than a panic at runtime, and the result is a
+/// constant that can initialize a `const` or `static`.
+///
+/// ```
+/// use semver::{version, Version};
+///
+/// const MSRV: Version = version!("1.68.0");
+///
+/// let beta = version!("1.2.3-beta.1");
+/// assert_eq!(beta, Version::parse("1.2.3-beta.1").unwrap());
+/// assert!(beta < MSRV);
+/// ```
+///
+/// ```compile_fail
+/// // error: invalid leading zero in patch version number
+/// let v = semver::version!("1.2.03");
+/// ```
+///
+/// # Limitations
+///
+/// A constant cannot own a heap allocation, so this accepts what
+/// [`Version::parse_inline`] accepts: pre-release and build metadata of up to
+/// 8 bytes each. A longer pre-release or build metadata is a compile error;
+/// use [`Version::parse`] for those.
+///
+/// ```compile_fail
+/// // error: pre-release identifier longer than 8 bytes cannot be stored without allocating
+/// let v = semver::version!("1.0.0-prerelease");
+/// ```
+#[macro_export]
+macro_rules! version {
+    ($version:expr $(,)?) => {{
+        const VERSION: $crate::Version = $crate::__private::version($version);
+        VERSION
+    }};
+}
+
+macro_rules! message {
+    ($pos:expr, $prefix:literal) => {
+        message!($pos, $prefix, "")
+    };
+    ($pos:expr, $prefix:literal, $suffix:literal) => {
+        match $pos {
+            Position::Major => concat!($prefix, "major version number", $suffix),
+            Position::Minor => concat!($prefix, "minor version number", $suffix),
+            Position::Patch => concat!($prefix, "patch version number", $suffix),
+            Position::Pre => concat!($prefix, "pre-release identifier", $suffix),
+            Position::Build => concat!($prefix, "build metadata", $suffix),
+        }
+    };
+}
+
+/// Implementation of `version!`.
+///
+/// # Panics
+///
+/// Panics if the text is not a valid version, or if its pre-release or build
+/// metadata is too long to be stored inline. In a const context the panic is a
+/// compile error.
+pub const fn version(text: &str) -> Version {
+    let input = text.as_bytes();
+    let parts = match parse::version_parts(input) {
+        Ok(parts) => parts,
+        Err(error) => panic!("{}", message(&error)),
+    };
+    Version {
+        major: parts.major,
+        minor: parts.minor,
+        patch: parts.patch,
+        pre: Prerelease {
+            identifier: inline(input, parts.pre, Position::Pre),
+        },
+        build: BuildMetadata {
+            identifier: inline(input, parts.build, Position::Build),
+        },
+    }
+}
+
+// The Display of the errors that version_parts returns, less the character.
+const fn message(error: &Error) -> &'static str {
+    match &error.kind {
+        ErrorKind::Empty => "empty string, expected a semver version",
+        ErrorKind::UnexpectedEnd(pos) => message!(pos, "unexpected end of input while parsing "),
+        ErrorKind::UnexpectedChar(pos, _) => message!(pos, "unexpected character while parsing "),
+        ErrorKind::UnexpectedCharAfter(pos, _) => message!(pos, "unexpected character after "),
+        ErrorKind::LeadingZero(pos) => message!(pos, "invalid leading zero in "),
+        ErrorKind::Overflow(pos) => message!(pos, "value of ", " exceeds u64::MAX"),
+        ErrorKind::EmptySegment(pos) => message!(pos, "empty identifier segment in "),
+        ErrorKind::InlineCapacity(pos) => message!(
+            pos,
+            "",
+            " longer than 8 bytes cannot be stored without allocating, use Version::parse"
+        ),
+        _ => "invalid semver version",
+    }
+}
+
+// Same as parse::inline_identifier, with a panic for the error.
+const fn inline(input: &[u8], range: (usize, usize), pos: Position) -> Identifier {
+    let (start, end) = range;
+    if start == end {
+        return Identifier::empty();
+    }
+    assert!(
+        end - start <= mem::size_of::<Identifier>(),
+        "{}",
+        message(&Error::new(ErrorKind::InlineCapacity(pos))),
+    );
+    let mut bytes = [0u8; mem::size_of::<Identifier>()];
+    let mut i = 0;
+    while start + i < end {
+        bytes[i] = input[start + i];
+        i += 1;
+    }
+    // SAFETY: the range is a nonempty run of identifier characters, as
+    // returned by version_parts, no longer than the inline repr.
+    unsafe { Identifier::new_inline_unchecked(bytes) }
+}
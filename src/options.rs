@@ -0,0 +1,154 @@
+use crate::error::{ErrorKind, Limit, Position};
+use crate::parse::{self, Error, MAX_COMPARATORS};
+use crate::{Comparator, Version, VersionReq};
+use alloc::vec::Vec;
+
+/// Limits on the size of input accepted by the parser.
+///
+/// The `parse` functions accept input of any length, up to a version
+/// requirement of 32 comparators. When the input comes from an untrusted
+/// source, `ParseOptions` bounds the work and memory spent on it by rejecting
+/// input that exceeds any of the limits. The error message names the limit
+/// that was exceeded.
+///
+/// # Example
+///
+/// ```
+/// use semver::{ParseOptions, VersionReq};
+///
+/// const OPTIONS: ParseOptions = ParseOptions::new()
+///     .max_input_len(256)
+///     .max_comparators(4)
+///     .max_identifiers(8)
+///     .max_identifier_len(32);
+///
+/// let req = VersionReq::parse_with(">=1.2.3, <2", &OPTIONS).unwrap();
+/// assert_eq!(req.comparators.len(), 2);
+///
+/// let error = VersionReq::parse_with(">1, >2, >3, >4, >5", &OPTIONS).unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "version req has more than 4 comparators, the limit set by max_comparators",
+/// );
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ParseOptions {
+    input_len: usize,
+    comparators: usize,
+    identifiers: usize,
+    identifier_len: usize,
+}
+
+impl ParseOptions {
+    /// Options with no limits, other than the 32 comparators in a version
+    /// requirement that `parse` also allows.
+    pub const fn new() -> Self {
+        ParseOptions {
+            input_len: usize::MAX,
+            comparators: MAX_COMPARATORS,
+            identifiers: usize::MAX,
+            identifier_len: usize::MAX,
+        }
+    }
+
+    /// Maximum length in bytes of the whole input string.
+    #[must_use]
+    pub const fn max_input_len(mut self, max: usize) -> Self {
+        self.input_len = max;
+        self
+    }
+
+    /// Maximum number of comparators in a version requirement.
+    #[must_use]
+    pub const fn max_comparators(mut self, max: usize) -> Self {
+        self.comparators = max;
+        self
+    }
+
+    /// Maximum number of dot-separated identifiers in a pre-release or in
+    /// build metadata. For example `alpha.1` has 2.
+    #[must_use]
+    pub const fn max_identifiers(mut self, max: usize) -> Self {
+        self.identifiers = max;
+        self
+    }
+
+    /// Maximum length in bytes of each dot-separated identifier in a
+    /// pre-release or in build metadata.
+    #[must_use]
+    pub const fn max_identifier_len(mut self, max: usize) -> Self {
+        self.identifier_len = max;
+        self
+    }
+
+    fn check_input(&self, text: &str) -> Result<(), Error> {
+        if text.len() > self.input_len {
+            return Err(Error::new(ErrorKind::ExceededLimit(
+                Limit::InputLen,
+                self.input_len,
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_identifiers(&self, text: &str, pos: Position) -> Result<(), Error> {
+        if text.is_empty() {
+            return Ok(());
+        }
+        let mut count = 0;
+        for identifier in text.split('.') {
+            count += 1;
+            if count > self.identifiers {
+                return Err(Error::new(ErrorKind::ExceededLimit(
+                    Limit::Identifiers(pos),
+                    self.identifiers,
+                )));
+            }
+            if identifier.len() > self.identifier_len {
+                return Err(Error::new(ErrorKind::ExceededLimit(
+                    Limit::IdentifierLen(pos),
+                    self.identifier_len,
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+pub(crate) fn version(text: &str, options: &ParseOptions) -> Result<Version, Error> {
+    options.check_input(text)?;
+    let version = parse::version(text)?;
+    options.check_identifiers(version.pre, Position::Pre)?;
+    options.check_identifiers(version.build, Position::Build)?;
+    Ok(version.to_version())
+}
+
+pub(crate) fn comparator(text: &str, options: &ParseOptions) -> Result<Comparator, Error> {
+    options.check_input(text)?;
+    let comparator = parse::comparator_ref(text)?;
+    options.check_identifiers(comparator.pre, Position::Pre)?;
+    Ok(comparator.to_comparator())
+}
+
+pub(crate) fn version_req(text: &str, options: &ParseOptions) -> Result<VersionReq, Error> {
+    options.check_input(text)?;
+    let mut comparators = Vec::new();
+    parse::comparators(text, |comparator| {
+        if comparators.len() == options.comparators {
+            return Err(Error::new(ErrorKind::ExceededLimit(
+                Limit::Comparators,
+                options.comparators,
+            )));
+        }
+        options.check_identifiers(comparator.pre, Position::Pre)?;
+        comparators.push(comparator.to_comparator());
+        Ok(())
+    })?;
+    Ok(VersionReq { comparators })
+}
@@ -0,0 +1,216 @@
+use crate::error::{ErrorKind, Packed, Position};
+use crate::parse::Error;
+use crate::{BuildMetadata, Prerelease, Version, VersionRef};
+use core::convert::TryFrom;
+
+macro_rules! packed_version {
+    ($(#[$attr:meta])* $name:ident($repr:ty), $field_bits:expr) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        pub struct $name {
+            // major, minor, patch of FIELD_BITS each, then 1 if the version
+            // has no pre-release, so that a pre-release sorts before the
+            // corresponding release.
+            bits: $repr,
+        }
+
+        impl $name {
+            const FIELD_BITS: u32 = $field_bits;
+            const FIELD_MASK: $repr = (1 << Self::FIELD_BITS) - 1;
+
+            /// The largest major, minor, or patch number that fits.
+            pub const MAX_FIELD: u64 = Self::FIELD_MASK as u64;
+
+            /// Pack a release version, or return `None` if any number is
+            /// greater than [`MAX_FIELD`][Self::MAX_FIELD].
+            pub const fn new(major: u64, minor: u64, patch: u64) -> Option<Self> {
+                if major > Self::MAX_FIELD || minor > Self::MAX_FIELD || patch > Self::MAX_FIELD {
+                    return None;
+                }
+                Some(Self::from_parts(major, minor, patch, false))
+            }
+
+            /// Pack the precedence of a version: its numbers and whether it
+            /// has a pre-release.
+            ///
+            /// The pre-release identifiers and build metadata are not
+            /// stored, so this is lossless only for versions that have
+            /// neither. Packed versions are ordered consistently with
+            /// [`Version::cmp_precedence`], except that all pre-releases of
+            /// the same major, minor, and patch compare equal.
+            ///
+            /// # Errors
+            ///
+            /// Fails if any number is greater than
+            /// [`MAX_FIELD`][Self::MAX_FIELD].
+            pub fn from_version(version: &Version) -> Result<Self, Error> {
+                Self::from_version_ref(&VersionRef::from(version))
+            }
+
+            /// Pack the precedence of a borrowed version. See
+            /// [`from_version`][Self::from_version].
+            pub fn from_version_ref(version: &VersionRef) -> Result<Self, Error> {
+                for (value, pos) in [
+                    (version.major, Position::Major),
+                    (version.minor, Position::Minor),
+                    (version.patch, Position::Patch),
+                ] {
+                    if value > Self::MAX_FIELD {
+                        return Err(Error::new(ErrorKind::Packed(Packed::Bits(pos, Self::FIELD_BITS))));
+                    }
+                }
+                Ok(Self::from_parts(
+                    version.major,
+                    version.minor,
+                    version.patch,
+                    !version.pre.is_empty(),
+                ))
+            }
+
+            const fn from_parts(major: u64, minor: u64, patch: u64, has_prerelease: bool) -> Self {
+                let bits = (major as $repr) << (2 * Self::FIELD_BITS + 1)
+                    | (minor as $repr) << (Self::FIELD_BITS + 1)
+                    | (patch as $repr) << 1
+                    | !has_prerelease as $repr;
+                $name { bits }
+            }
+
+            /// The major version number.
+            pub const fn major(&self) -> u64 {
+                (self.bits >> (2 * Self::FIELD_BITS + 1) & Self::FIELD_MASK) as u64
+            }
+
+            /// The minor version number.
+            pub const fn minor(&self) -> u64 {
+                (self.bits >> (Self::FIELD_BITS + 1) & Self::FIELD_MASK) as u64
+            }
+
+            /// The patch version number.
+            pub const fn patch(&self) -> u64 {
+                (self.bits >> 1 & Self::FIELD_MASK) as u64
+            }
+
+            /// Whether the packed version stands for some pre-release of its
+            /// major, minor, and patch, which cannot be recovered.
+            pub const fn has_prerelease(&self) -> bool {
+                self.bits & 1 == 0
+            }
+
+            /// The integer representation, which orders the same way as the
+            /// packed versions.
+            pub const fn to_bits(self) -> $repr {
+                self.bits
+            }
+
+            /// Reinterpret the output of [`to_bits`][Self::to_bits], or
+            /// return `None` if the integer uses bits outside of the layout.
+            pub const fn from_bits(bits: $repr) -> Option<Self> {
+                if bits >> (3 * Self::FIELD_BITS) >> 1 != 0 {
+                    return None;
+                }
+                Some($name { bits })
+            }
+
+            /// Unpack into a `Version`, or return `None` if the version has a
+            /// pre-release.
+            pub fn to_version(&self) -> Option<Version> {
+                if self.has_prerelease() {
+                    return None;
+                }
+                Some(Version {
+                    major: self.major(),
+                    minor: self.minor(),
+                    patch: self.patch(),
+                    pre: Prerelease::EMPTY,
+                    build: BuildMetadata::EMPTY,
+                })
+            }
+
+            /// Unpack into a `VersionRef` for use with
+            /// [`VersionReq::matches_ref`], or return `None` if the version
+            /// has a pre-release.
+            ///
+            /// [`VersionReq::matches_ref`]: crate::VersionReq::matches_ref
+            pub const fn to_version_ref(&self) -> Option<VersionRef<'static>> {
+                if self.has_prerelease() {
+                    return None;
+                }
+                Some(VersionRef {
+                    major: self.major(),
+                    minor: self.minor(),
+                    patch: self.patch(),
+                    pre: "",
+                    build: "",
+                })
+            }
+        }
+
+        /// Packs a version losslessly, failing if it has a pre-release or
+        /// build metadata, or any number is too large.
+        impl TryFrom<&Version> for $name {
+            type Error = Error;
+
+            fn try_from(version: &Version) -> Result<Self, Self::Error> {
+                if !version.pre.is_empty() {
+                    return Err(Error::new(ErrorKind::Packed(Packed::Prerelease)));
+                }
+                if !version.build.is_empty() {
+                    return Err(Error::new(ErrorKind::Packed(Packed::HasBuildMetadata)));
+                }
+                $name::from_version(version)
+            }
+        }
+
+        /// Unpacks a version, failing if it has a pre-release.
+        impl TryFrom<$name> for Version {
+            type Error = Error;
+
+            fn try_from(version: $name) -> Result<Self, Self::Error> {
+                match version.to_version() {
+                    Some(version) => Ok(version),
+                    None => Err(Error::new(ErrorKind::Packed(Packed::Prerelease))),
+                }
+            }
+        }
+    };
+}
+
+packed_version! {
+    /// **SemVer version** packed into a `u64`, comparable with a single
+    /// integer comparison.
+    ///
+    /// The major, minor, and patch numbers take 21 bits each, so they can be
+    /// at most 2097151. The remaining bit records whether the version has a
+    /// pre-release, but not what the pre-release is, and build metadata is
+    /// not stored at all. See [`PackedVersion128`] for larger numbers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use semver::{PackedVersion, Version, VersionReq};
+    ///
+    /// let mut versions = ["1.10.0", "1.2.0", "1.10.0-rc.1", "0.9.7"]
+    ///     .map(|v| PackedVersion::from_version(&Version::parse(v).unwrap()).unwrap());
+    /// versions.sort();
+    ///
+    /// let req = VersionReq::parse("^1.2").unwrap();
+    /// let newest = versions
+    ///     .iter()
+    ///     .rev()
+    ///     .find_map(|v| v.to_version_ref().filter(|v| req.matches_ref(v)))
+    ///     .unwrap();
+    /// assert_eq!(newest.to_string(), "1.10.0");
+    /// assert!(versions[2].has_prerelease());
+    /// ```
+    PackedVersion(u64), 21
+}
+
+packed_version! {
+    /// **SemVer version** packed into a `u128`, comparable with a single
+    /// integer comparison.
+    ///
+    /// This is the same as [`PackedVersion`] but with 42 bits for each of
+    /// the major, minor, and patch numbers, enough for date-based numbers
+    /// such as `20240101`.
+    PackedVersion128(u128), 42
+}
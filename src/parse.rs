@@ -1,9 +1,25 @@
 use crate::error::{ErrorKind, Position};
 use crate::identifier::Identifier;
-use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+use crate::{
+    BuildMetadata, Comparator, ComparatorRef, FixedVersionReq, Op, Prerelease, Version, VersionRef,
+    VersionReq,
+};
 use alloc::vec::Vec;
+use core::mem;
 use core::str::FromStr;
 
+pub(crate) const MAX_COMPARATORS: usize = 32;
+
+// The `?` operator, which is not available in a const fn.
+macro_rules! tri {
+    ($result:expr) => {
+        match $result {
+            Ok(ok) => ok,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
 /// Error parsing a SemVer version or version requirement.
 ///
 /// # Example
@@ -26,58 +42,7 @@ impl FromStr for Version {
     type Err = Error;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        if text.is_empty() {
-            return Err(Error::new(ErrorKind::Empty));
-        }
-
-        let mut pos = Position::Major;
-        let (major, text) = numeric_identifier(text, pos)?;
-        let text = dot(text, pos)?;
-
-        pos = Position::Minor;
-        let (minor, text) = numeric_identifier(text, pos)?;
-        let text = dot(text, pos)?;
-
-        pos = Position::Patch;
-        let (patch, text) = numeric_identifier(text, pos)?;
-
-        if text.is_empty() {
-            return Ok(Version::new(major, minor, patch));
-        }
-
-        let (pre, text) = if let Some(text) = text.strip_prefix('-') {
-            pos = Position::Pre;
-            let (pre, text) = prerelease_identifier(text)?;
-            if pre.is_empty() {
-                return Err(Error::new(ErrorKind::EmptySegment(pos)));
-            }
-            (pre, text)
-        } else {
-            (Prerelease::EMPTY, text)
-        };
-
-        let (build, text) = if let Some(text) = text.strip_prefix('+') {
-            pos = Position::Build;
-            let (build, text) = build_identifier(text)?;
-            if build.is_empty() {
-                return Err(Error::new(ErrorKind::EmptySegment(pos)));
-            }
-            (build, text)
-        } else {
-            (BuildMetadata::EMPTY, text)
-        };
-
-        if let Some(unexpected) = text.chars().next() {
-            return Err(Error::new(ErrorKind::UnexpectedCharAfter(pos, unexpected)));
-        }
-
-        Ok(Version {
-            major,
-            minor,
-            patch,
-            pre,
-            build,
-        })
+        Ok(version(text)?.to_version())
     }
 }
 
@@ -109,13 +74,21 @@ impl FromStr for Comparator {
     type Err = Error;
 
     fn from_str(text: &str) -> Result<Self, Self::Err> {
-        let text = text.trim_start_matches(' ');
-        let (comparator, pos, rest) = comparator(text)?;
-        if !rest.is_empty() {
-            let unexpected = rest.chars().next().unwrap();
-            return Err(Error::new(ErrorKind::UnexpectedCharAfter(pos, unexpected)));
-        }
-        Ok(comparator)
+        Ok(comparator_ref(text)?.to_comparator())
+    }
+}
+
+impl<const N: usize> FromStr for FixedVersionReq<N> {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut req = FixedVersionReq::new();
+        comparators(text, |comparator| {
+            let comparator = comparator.to_inline_comparator()?;
+            req.push(comparator)
+                .map_err(|_| Error::new(ErrorKind::FixedCapacity(N)))
+        })?;
+        Ok(req)
     }
 }
 
@@ -144,7 +117,7 @@ impl FromStr for BuildMetadata {
 }
 
 impl Error {
-    fn new(kind: ErrorKind) -> Self {
+    pub(crate) const fn new(kind: ErrorKind) -> Self {
         Error { kind }
     }
 }
@@ -153,31 +126,133 @@ impl Op {
     const DEFAULT: Self = Op::Caret;
 }
 
+pub(crate) fn version(text: &str) -> Result<VersionRef<'_>, Error> {
+    let parts = version_parts(text.as_bytes())?;
+    Ok(VersionRef {
+        major: parts.major,
+        minor: parts.minor,
+        patch: parts.patch,
+        pre: &text[parts.pre.0..parts.pre.1],
+        build: &text[parts.build.0..parts.build.1],
+    })
+}
+
+// A parsed version, with pre-release and build metadata as byte ranges of the
+// input. This is what `version_parts` returns in place of a VersionRef, since
+// a str cannot be sliced in a const fn.
+#[derive(Copy, Clone)]
+pub(crate) struct VersionParts {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: (usize, usize),
+    pub build: (usize, usize),
+}
+
+// The grammar of a version, written to be const evaluable so that version!
+// can share it. The input must be the bytes of a str.
+pub(crate) const fn version_parts(input: &[u8]) -> Result<VersionParts, Error> {
+    if input.is_empty() {
+        return Err(Error::new(ErrorKind::Empty));
+    }
+
+    let mut pos = Position::Major;
+    let (major, i) = tri!(numeric_identifier_at(input, 0, pos));
+    let i = tri!(dot(input, i, pos));
+
+    pos = Position::Minor;
+    let (minor, i) = tri!(numeric_identifier_at(input, i, pos));
+    let i = tri!(dot(input, i, pos));
+
+    pos = Position::Patch;
+    let (patch, mut i) = tri!(numeric_identifier_at(input, i, pos));
+
+    let mut pre = (i, i);
+    if i < input.len() && input[i] == b'-' {
+        pos = Position::Pre;
+        let end = tri!(identifier_at(input, i + 1, pos));
+        if end == i + 1 {
+            return Err(Error::new(ErrorKind::EmptySegment(pos)));
+        }
+        pre = (i + 1, end);
+        i = end;
+    }
+
+    let mut build = (i, i);
+    if i < input.len() && input[i] == b'+' {
+        pos = Position::Build;
+        let end = tri!(identifier_at(input, i + 1, pos));
+        if end == i + 1 {
+            return Err(Error::new(ErrorKind::EmptySegment(pos)));
+        }
+        build = (i + 1, end);
+        i = end;
+    }
+
+    if i < input.len() {
+        return Err(Error::new(ErrorKind::UnexpectedCharAfter(
+            pos,
+            char_at(input, i),
+        )));
+    }
+
+    Ok(VersionParts {
+        major,
+        minor,
+        patch,
+        pre,
+        build,
+    })
+}
+
+pub(crate) fn comparator_ref(text: &str) -> Result<ComparatorRef<'_>, Error> {
+    let text = text.trim_start_matches(' ');
+    let (comparator, pos, rest) = comparator(text)?;
+    if !rest.is_empty() {
+        let unexpected = rest.chars().next().unwrap();
+        return Err(Error::new(ErrorKind::UnexpectedCharAfter(pos, unexpected)));
+    }
+    Ok(comparator)
+}
+
 fn numeric_identifier(input: &str, pos: Position) -> Result<(u64, &str), Error> {
-    let mut len = 0;
+    let (value, len) = numeric_identifier_at(input.as_bytes(), 0, pos)?;
+    Ok((value, &input[len..]))
+}
+
+const fn numeric_identifier_at(
+    input: &[u8],
+    start: usize,
+    pos: Position,
+) -> Result<(u64, usize), Error> {
+    let mut i = start;
     let mut value = 0u64;
 
-    while let Some(&digit) = input.as_bytes().get(len) {
+    while i < input.len() {
+        let digit = input[i];
         if digit < b'0' || digit > b'9' {
             break;
         }
-        if value == 0 && len > 0 {
+        if value == 0 && i > start {
             return Err(Error::new(ErrorKind::LeadingZero(pos)));
         }
-        match value
-            .checked_mul(10)
-            .and_then(|value| value.checked_add((digit - b'0') as u64))
-        {
-            Some(sum) => value = sum,
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((digit - b'0') as u64) {
+                Some(sum) => sum,
+                None => return Err(Error::new(ErrorKind::Overflow(pos))),
+            },
             None => return Err(Error::new(ErrorKind::Overflow(pos))),
-        }
-        len += 1;
+        };
+        i += 1;
     }
 
-    if len > 0 {
-        Ok((value, &input[len..]))
-    } else if let Some(unexpected) = input[len..].chars().next() {
-        Err(Error::new(ErrorKind::UnexpectedChar(pos, unexpected)))
+    if i > start {
+        Ok((value, i))
+    } else if i < input.len() {
+        Err(Error::new(ErrorKind::UnexpectedChar(
+            pos,
+            char_at(input, i),
+        )))
     } else {
         Err(Error::new(ErrorKind::UnexpectedEnd(pos)))
     }
@@ -195,64 +270,110 @@ fn wildcard(input: &str) -> Option<(char, &str)> {
     }
 }
 
-fn dot(input: &str, pos: Position) -> Result<&str, Error> {
-    if let Some(rest) = input.strip_prefix('.') {
-        Ok(rest)
-    } else if let Some(unexpected) = input.chars().next() {
-        Err(Error::new(ErrorKind::UnexpectedCharAfter(pos, unexpected)))
-    } else {
+const fn dot(input: &[u8], i: usize, pos: Position) -> Result<usize, Error> {
+    if i == input.len() {
         Err(Error::new(ErrorKind::UnexpectedEnd(pos)))
+    } else if input[i] == b'.' {
+        Ok(i + 1)
+    } else {
+        Err(Error::new(ErrorKind::UnexpectedCharAfter(
+            pos,
+            char_at(input, i),
+        )))
+    }
+}
+
+// The char starting at byte offset i, which must be a char boundary of the
+// str whose bytes are the input.
+const fn char_at(input: &[u8], i: usize) -> char {
+    let (mut ch, len) = match input[i] {
+        byte @ 0x00..=0x7F => return byte as char,
+        byte @ 0xC0..=0xDF => ((byte & 0x1F) as u32, 2),
+        byte @ 0xE0..=0xEF => ((byte & 0x0F) as u32, 3),
+        byte => ((byte & 0x07) as u32, 4),
+    };
+    let mut k = 1;
+    while k < len {
+        ch = ch << 6 | (input[i + k] & 0x3F) as u32;
+        k += 1;
+    }
+    match char::from_u32(ch) {
+        Some(ch) => ch,
+        None => char::REPLACEMENT_CHARACTER,
     }
 }
 
 fn prerelease_identifier(input: &str) -> Result<(Prerelease, &str), Error> {
     let (string, rest) = identifier(input, Position::Pre)?;
-    let identifier = unsafe { Identifier::new_unchecked(string) };
+    let identifier = new_identifier(string);
     Ok((Prerelease { identifier }, rest))
 }
 
 fn build_identifier(input: &str) -> Result<(BuildMetadata, &str), Error> {
     let (string, rest) = identifier(input, Position::Build)?;
-    let identifier = unsafe { Identifier::new_unchecked(string) };
+    let identifier = new_identifier(string);
     Ok((BuildMetadata { identifier }, rest))
 }
 
+// The string must be the output of `identifier`.
+pub(crate) fn new_identifier(string: &str) -> Identifier {
+    // SAFETY: the output of `identifier` is ASCII without \0 bytes.
+    unsafe { Identifier::new_unchecked(string) }
+}
+
+// Like `new_identifier`, but fails instead of allocating if the string is too
+// long for the inline repr.
+pub(crate) fn inline_identifier(string: &str, pos: Position) -> Result<Identifier, Error> {
+    if string.len() > mem::size_of::<Identifier>() {
+        return Err(Error::new(ErrorKind::InlineCapacity(pos)));
+    }
+    Ok(new_identifier(string))
+}
+
 fn identifier(input: &str, pos: Position) -> Result<(&str, &str), Error> {
+    let len = identifier_at(input.as_bytes(), 0, pos)?;
+    Ok(input.split_at(len))
+}
+
+// Returns the end offset of the dot-separated identifiers starting at start.
+const fn identifier_at(input: &[u8], start: usize, pos: Position) -> Result<usize, Error> {
     let mut accumulated_len = 0;
     let mut segment_len = 0;
     let mut segment_has_nondigit = false;
 
     loop {
-        match input.as_bytes().get(accumulated_len + segment_len) {
-            Some(b'A'..=b'Z') | Some(b'a'..=b'z') | Some(b'-') => {
+        let i = start + accumulated_len + segment_len;
+        let boundary = if i < input.len() { input[i] } else { 0 };
+        match boundary {
+            b'A'..=b'Z' | b'a'..=b'z' | b'-' => {
                 segment_len += 1;
                 segment_has_nondigit = true;
             }
-            Some(b'0'..=b'9') => {
+            b'0'..=b'9' => {
                 segment_len += 1;
             }
             boundary => {
                 if segment_len == 0 {
-                    if accumulated_len == 0 && boundary != Some(&b'.') {
-                        return Ok(("", input));
+                    if accumulated_len == 0 && boundary != b'.' {
+                        return Ok(start);
                     } else {
                         return Err(Error::new(ErrorKind::EmptySegment(pos)));
                     }
                 }
-                if pos == Position::Pre
+                if matches!(pos, Position::Pre)
                     && segment_len > 1
                     && !segment_has_nondigit
-                    && input[accumulated_len..].starts_with('0')
+                    && input[start + accumulated_len] == b'0'
                 {
                     return Err(Error::new(ErrorKind::LeadingZero(pos)));
                 }
                 accumulated_len += segment_len;
-                if boundary == Some(&b'.') {
+                if boundary == b'.' {
                     accumulated_len += 1;
                     segment_len = 0;
                     segment_has_nondigit = false;
                 } else {
-                    return Ok(input.split_at(accumulated_len));
+                    return Ok(start + accumulated_len);
                 }
             }
         }
@@ -284,7 +405,7 @@ fn op(input: &str) -> (Op, &str) {
     }
 }
 
-fn comparator(input: &str) -> Result<(Comparator, Position, &str), Error> {
+fn comparator(input: &str) -> Result<(ComparatorRef<'_>, Position, &str), Error> {
     let (mut op, text) = op(input);
     let default_op = input.len() == text.len();
     let text = text.trim_start_matches(' ');
@@ -329,19 +450,19 @@ fn comparator(input: &str) -> Result<(Comparator, Position, &str), Error> {
     let (pre, text) = if patch.is_some() && text.starts_with('-') {
         pos = Position::Pre;
         let text = &text[1..];
-        let (pre, text) = prerelease_identifier(text)?;
+        let (pre, text) = identifier(text, pos)?;
         if pre.is_empty() {
             return Err(Error::new(ErrorKind::EmptySegment(pos)));
         }
         (pre, text)
     } else {
-        (Prerelease::EMPTY, text)
+        ("", text)
     };
 
     let text = if patch.is_some() && text.starts_with('+') {
         pos = Position::Build;
         let text = &text[1..];
-        let (build, text) = build_identifier(text)?;
+        let (build, text) = identifier(text, pos)?;
         if build.is_empty() {
             return Err(Error::new(ErrorKind::EmptySegment(pos)));
         }
@@ -352,7 +473,7 @@ fn comparator(input: &str) -> Result<(Comparator, Position, &str), Error> {
 
     let text = text.trim_start_matches(' ');
 
-    let comparator = Comparator {
+    let comparator = ComparatorRef {
         op,
         major,
         minor,
@@ -377,6 +498,8 @@ fn version_req(input: &str, out: &mut Vec<Comparator>, depth: usize) -> Result<u
         }
     };
 
+    let comparator = comparator.to_comparator();
+
     if text.is_empty() {
         out.reserve_exact(depth + 1);
         unsafe { out.as_mut_ptr().add(depth).write(comparator) }
@@ -390,7 +513,6 @@ fn version_req(input: &str, out: &mut Vec<Comparator>, depth: usize) -> Result<u
         return Err(Error::new(ErrorKind::ExpectedCommaFound(pos, unexpected)));
     };
 
-    const MAX_COMPARATORS: usize = 32;
     if depth + 1 == MAX_COMPARATORS {
         return Err(Error::new(ErrorKind::ExcessiveComparators));
     }
@@ -402,3 +524,52 @@ fn version_req(input: &str, out: &mut Vec<Comparator>, depth: usize) -> Result<u
     unsafe { out.as_mut_ptr().add(depth).write(comparator) }
     Ok(len)
 }
+
+// Parse a version requirement one comparator at a time, for storage that is
+// not a Vec. Unlike `version_req` this places no limit on the number of
+// comparators; that is up to the callback.
+pub(crate) fn comparators<'a>(
+    text: &'a str,
+    mut push: impl FnMut(ComparatorRef<'a>) -> Result<(), Error>,
+) -> Result<(), Error> {
+    let text = text.trim_start_matches(' ');
+    if let Some((ch, text)) = wildcard(text) {
+        let rest = text.trim_start_matches(' ');
+        if rest.is_empty() {
+            return Ok(());
+        } else if rest.starts_with(',') {
+            return Err(Error::new(ErrorKind::WildcardNotTheOnlyComparator(ch)));
+        } else {
+            return Err(Error::new(ErrorKind::UnexpectedAfterWildcard));
+        }
+    }
+
+    let mut input = text;
+    loop {
+        let (comparator, pos, text) = match comparator(input) {
+            Ok(success) => success,
+            Err(mut error) => {
+                if let Some((ch, mut rest)) = wildcard(input) {
+                    rest = rest.trim_start_matches(' ');
+                    if rest.is_empty() || rest.starts_with(',') {
+                        error.kind = ErrorKind::WildcardNotTheOnlyComparator(ch);
+                    }
+                }
+                return Err(error);
+            }
+        };
+
+        push(comparator)?;
+
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        input = if let Some(text) = text.strip_prefix(',') {
+            text.trim_start_matches(' ')
+        } else {
+            let unexpected = text.chars().next().unwrap();
+            return Err(Error::new(ErrorKind::ExpectedCommaFound(pos, unexpected)));
+        };
+    }
+}
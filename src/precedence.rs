@@ -0,0 +1,58 @@
+use crate::Version;
+use core::cmp::Ordering;
+
+/// Wrapper that compares, hashes, and tests a version for equality by
+/// precedence only, disregarding build metadata.
+///
+/// The `Eq`, `Hash` and `Ord` impls of [`Version`] take build metadata into
+/// account so that they are consistent with one another, but SemVer
+/// precedence and crates.io consider `1.0.0+a` and `1.0.0+b` the same release.
+/// `Precedence` provides the latter behavior consistently across all three, for
+/// use as the key of a `HashSet` or `BTreeMap`. It is implemented for
+/// [`Version`] and [`VersionRef`][crate::VersionRef].
+///
+/// # Example
+///
+/// ```
+/// use semver::{Precedence, Version};
+/// use std::collections::HashSet;
+///
+/// let mut releases = HashSet::new();
+/// for v in ["1.0.0+a", "1.0.0+b", "1.0.0-rc.1", "1.0.0"] {
+///     releases.insert(Precedence(Version::parse(v).unwrap()));
+/// }
+/// assert_eq!(releases.len(), 2);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(transparent)]
+pub struct Precedence<T>(pub T);
+
+/// Move the first of each run of consecutive versions that have the same
+/// precedence to the front of the slice, and return how many there are.
+///
+/// After sorting with either [`Ord`] or [`Version::cmp_precedence`], versions
+/// with the same precedence are adjacent, and the returned prefix holds one of
+/// each, in their original order. The order of the versions after the prefix
+/// is unspecified. For a `Vec`, follow it with `truncate` to drop the
+/// duplicates.
+///
+/// # Example
+///
+/// ```
+/// use semver::Version;
+///
+/// let mut versions = ["1.0.0+b", "1.0.0", "0.9.0", "1.0.0+a"].map(|v| Version::parse(v).unwrap());
+/// versions.sort();
+/// let len = semver::dedup_by_precedence(&mut versions);
+/// assert_eq!(versions[..len], [Version::new(0, 9, 0), Version::new(1, 0, 0)]);
+/// ```
+pub fn dedup_by_precedence(versions: &mut [Version]) -> usize {
+    let mut len = 0;
+    for i in 0..versions.len() {
+        if len == 0 || versions[len - 1].cmp_precedence(&versions[i]) != Ordering::Equal {
+            versions.swap(len, i);
+            len += 1;
+        }
+    }
+    len
+}
@@ -0,0 +1,206 @@
+//! [Proptest] strategies for generating versions and requirements.
+//!
+//! With the `proptest` feature enabled, [`Version`], [`VersionReq`],
+//! [`Comparator`], [`Op`], [`Prerelease`] and [`BuildMetadata`] implement
+//! proptest's [`Arbitrary`], so `any::<Version>()` produces valid versions.
+//! Numbers are biased toward small values so that generated versions and
+//! requirements frequently interact with one another.
+//!
+//! In addition, [`matching`] and [`not_matching`] generate versions on either
+//! side of a particular requirement, for testing code that makes decisions
+//! based on whether a version matches.
+//!
+//! [Proptest]: https://docs.rs/proptest
+//!
+//! # Example
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use semver::{Version, VersionReq};
+//!
+//! proptest! {
+//!     # /*
+//!     #[test]
+//!     # */
+//!     fn display_roundtrip(version in any::<Version>()) {
+//!         prop_assert_eq!(Version::parse(&version.to_string()).unwrap(), version);
+//!     }
+//!
+//!     # /*
+//!     #[test]
+//!     # */
+//!     fn caret_never_crosses_major(
+//!         version in semver::proptest::matching(&VersionReq::parse("^1.2.3").unwrap()),
+//!     ) {
+//!         prop_assert_eq!(version.major, 1);
+//!     }
+//! }
+//! #
+//! # display_roundtrip();
+//! # caret_never_crosses_major();
+//! ```
+
+use crate::generate::{self, Identifier, Perturbation};
+use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+use alloc::string::String;
+use alloc::vec::Vec;
+use proptest::arbitrary::{any, Arbitrary};
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prop_oneof;
+use proptest::sample::{select, Index};
+use proptest::strategy::{BoxedStrategy, Just, Strategy};
+
+/// Strategy producing versions that match the given requirement.
+///
+/// If no version matches the requirement, as in `>1, <1`, the strategy
+/// rejects every value it generates, and proptest fails the test with too
+/// many rejections.
+pub fn matching(req: &VersionReq) -> impl Strategy<Value = Version> {
+    side(req, true)
+}
+
+/// Strategy producing versions that do not match the given requirement.
+///
+/// If every version matches the requirement, as in `*`, the strategy rejects
+/// every value it generates.
+pub fn not_matching(req: &VersionReq) -> impl Strategy<Value = Version> {
+    side(req, false)
+}
+
+fn side(req: &VersionReq, want_match: bool) -> impl Strategy<Value = Version> {
+    let seeds = generate::seeds(req);
+    let seeds = if want_match {
+        seeds.matching
+    } else {
+        seeds.not_matching
+    };
+    let satisfiable = !seeds.is_empty();
+    let reason = if want_match {
+        "no version matches the requirement"
+    } else {
+        "every version matches the requirement"
+    };
+    let req = req.clone();
+    (any::<Index>(), perturbation())
+        .prop_filter(reason, move |_| satisfiable)
+        .prop_map(move |(index, perturbation)| {
+            let index = index.index(seeds.len());
+            generate::pick(&req, &seeds, want_match, index, &perturbation)
+        })
+}
+
+fn number() -> impl Strategy<Value = u64> {
+    prop_oneof![4 => 0..4u64, 2 => 0..100u64, 1 => any::<u64>()]
+}
+
+fn identifiers() -> impl Strategy<Value = String> {
+    let identifier = prop_oneof![
+        number().prop_map(Identifier::Numeric),
+        vec(any::<u8>(), 1..8).prop_map(Identifier::Alphanumeric),
+    ];
+    vec(identifier, 1..4).prop_map(generate::dotted)
+}
+
+fn perturbation() -> impl Strategy<Value = Perturbation> {
+    (
+        any::<u8>(),
+        number(),
+        any::<Prerelease>(),
+        any::<BuildMetadata>(),
+    )
+        .prop_map(|(kind, amount, pre, build)| Perturbation {
+            kind,
+            amount,
+            pre,
+            build,
+        })
+}
+
+impl Arbitrary for Version {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (
+            number(),
+            number(),
+            number(),
+            any::<Prerelease>(),
+            any::<BuildMetadata>(),
+        )
+            .prop_map(|(major, minor, patch, pre, build)| Version {
+                major,
+                minor,
+                patch,
+                pre,
+                build,
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for Prerelease {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(Prerelease::EMPTY),
+            identifiers().prop_map(|pre| generate::prerelease(&pre)),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for BuildMetadata {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            2 => Just(BuildMetadata::EMPTY),
+            1 => identifiers().prop_map(|build| generate::build_metadata(&build)),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for Op {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        select(&generate::OPS[..]).boxed()
+    }
+}
+
+impl Arbitrary for Comparator {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (
+            any::<Op>(),
+            number(),
+            option::of(number()),
+            option::of(number()),
+            any::<Prerelease>(),
+        )
+            .prop_map(|(op, major, minor, patch, pre)| {
+                generate::comparator(op, major, minor, patch, pre)
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for VersionReq {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        vec(any::<Comparator>(), 0..4)
+            .prop_map(|comparators: Vec<Comparator>| VersionReq { comparators })
+            .boxed()
+    }
+}
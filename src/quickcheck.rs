@@ -0,0 +1,205 @@
+//! [QuickCheck] generators for versions and requirements.
+//!
+//! With the `quickcheck` feature enabled, [`Version`], [`VersionReq`],
+//! [`Comparator`], [`Op`], [`Prerelease`] and [`BuildMetadata`] implement
+//! quickcheck's [`Arbitrary`], producing only valid values. Numbers are biased
+//! toward small values so that generated versions and requirements frequently
+//! interact with one another.
+//!
+//! In addition, [`matching`] and [`not_matching`] generate versions on either
+//! side of a particular requirement.
+//!
+//! [QuickCheck]: https://docs.rs/quickcheck
+//!
+//! # Example
+//!
+//! ```
+//! use quickcheck::{Gen, QuickCheck};
+//! use semver::{Version, VersionReq};
+//!
+//! fn prop(version: Version) -> bool {
+//!     Version::parse(&version.to_string()).unwrap() == version
+//! }
+//!
+//! QuickCheck::new().quickcheck(prop as fn(Version) -> bool);
+//!
+//! let req = VersionReq::parse(">=1.2.3, <1.8.0").unwrap();
+//! let mut g = Gen::new(10);
+//! for _ in 0..100 {
+//!     assert!(req.matches(&semver::quickcheck::matching(&mut g, &req).unwrap()));
+//!     assert!(!req.matches(&semver::quickcheck::not_matching(&mut g, &req).unwrap()));
+//! }
+//! ```
+
+use crate::generate::{self, Identifier, Perturbation};
+use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use quickcheck::{Arbitrary, Gen};
+
+/// Generate a version that matches the given requirement, or `None` if no
+/// version matches it, as in `>1, <1`.
+pub fn matching(g: &mut Gen, req: &VersionReq) -> Option<Version> {
+    side(g, req, true)
+}
+
+/// Generate a version that does not match the given requirement, or `None` if
+/// every version matches it, as in `*`.
+pub fn not_matching(g: &mut Gen, req: &VersionReq) -> Option<Version> {
+    side(g, req, false)
+}
+
+fn side(g: &mut Gen, req: &VersionReq, want_match: bool) -> Option<Version> {
+    let seeds = generate::seeds(req);
+    let seeds = if want_match {
+        seeds.matching
+    } else {
+        seeds.not_matching
+    };
+    if seeds.is_empty() {
+        return None;
+    }
+    let index = usize::arbitrary(g);
+    let perturbation = Perturbation {
+        kind: u8::arbitrary(g),
+        amount: number(g),
+        pre: Prerelease::arbitrary(g),
+        build: BuildMetadata::arbitrary(g),
+    };
+    Some(generate::pick(
+        req,
+        &seeds,
+        want_match,
+        index,
+        &perturbation,
+    ))
+}
+
+fn number(g: &mut Gen) -> u64 {
+    match u8::arbitrary(g) % 7 {
+        0..=3 => u64::arbitrary(g) % 4,
+        4 | 5 => u64::arbitrary(g) % 100,
+        _ => u64::arbitrary(g),
+    }
+}
+
+fn identifiers(g: &mut Gen) -> String {
+    let len = 1 + usize::arbitrary(g) % 3;
+    generate::dotted((0..len).map(|_| {
+        if bool::arbitrary(g) {
+            Identifier::Numeric(number(g))
+        } else {
+            let len = 1 + usize::arbitrary(g) % 7;
+            Identifier::Alphanumeric((0..len).map(|_| u8::arbitrary(g)).collect())
+        }
+    }))
+}
+
+impl Arbitrary for Version {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Version {
+            major: number(g),
+            minor: number(g),
+            patch: number(g),
+            pre: Prerelease::arbitrary(g),
+            build: BuildMetadata::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let mut shrunk = Vec::new();
+        if !self.build.is_empty() {
+            shrunk.push(Version {
+                build: BuildMetadata::EMPTY,
+                ..self.clone()
+            });
+        }
+        if !self.pre.is_empty() {
+            shrunk.push(Version {
+                pre: Prerelease::EMPTY,
+                ..self.clone()
+            });
+        }
+        for major in self.major.shrink() {
+            shrunk.push(Version {
+                major,
+                ..self.clone()
+            });
+        }
+        for minor in self.minor.shrink() {
+            shrunk.push(Version {
+                minor,
+                ..self.clone()
+            });
+        }
+        for patch in self.patch.shrink() {
+            shrunk.push(Version {
+                patch,
+                ..self.clone()
+            });
+        }
+        Box::new(shrunk.into_iter())
+    }
+}
+
+impl Arbitrary for Prerelease {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if bool::arbitrary(g) {
+            Prerelease::EMPTY
+        } else {
+            generate::prerelease(&identifiers(g))
+        }
+    }
+}
+
+impl Arbitrary for BuildMetadata {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if u8::arbitrary(g) % 3 != 0 {
+            BuildMetadata::EMPTY
+        } else {
+            generate::build_metadata(&identifiers(g))
+        }
+    }
+}
+
+impl Arbitrary for Op {
+    fn arbitrary(g: &mut Gen) -> Self {
+        *g.choose(&generate::OPS).unwrap()
+    }
+}
+
+impl Arbitrary for Comparator {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let op = Op::arbitrary(g);
+        let major = number(g);
+        let minor = if bool::arbitrary(g) {
+            Some(number(g))
+        } else {
+            None
+        };
+        let patch = if bool::arbitrary(g) {
+            Some(number(g))
+        } else {
+            None
+        };
+        let pre = Prerelease::arbitrary(g);
+        generate::comparator(op, major, minor, patch, pre)
+    }
+}
+
+impl Arbitrary for VersionReq {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = usize::arbitrary(g) % 4;
+        let comparators = (0..len).map(|_| Comparator::arbitrary(g)).collect();
+        VersionReq { comparators }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            self.comparators
+                .shrink()
+                .map(|comparators| VersionReq { comparators }),
+        )
+    }
+}
@@ -0,0 +1,171 @@
+// JSON Schema for the string representation of each type, with a regex
+// pattern that accepts exactly the strings accepted by the corresponding
+// FromStr impl in parse.rs.
+
+use crate::parse;
+use crate::{BuildMetadata, Comparator, Prerelease, Version, VersionReq};
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::String;
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+// Decimal u64 without leading zeros: "0", or up to 19 digits, or 20 digits
+// that do not exceed u64::MAX.
+fn number() -> String {
+    fn at_most(max: &[u8], lowest: u8) -> String {
+        let first = max[0];
+        let rest = &max[1..];
+        if rest.is_empty() {
+            return format!("[{}-{}]", lowest as char, first as char);
+        }
+        let exact = format!("{}{}", first as char, at_most(rest, b'0'));
+        if first == lowest {
+            exact
+        } else {
+            format!(
+                "(?:[{}-{}][0-9]{{{}}}|{})",
+                lowest as char,
+                (first - 1) as char,
+                rest.len(),
+                exact,
+            )
+        }
+    }
+
+    let max = format!("{}", u64::MAX);
+    format!(
+        "(?:0|[1-9][0-9]{{0,{}}}|{})",
+        max.len() - 2,
+        at_most(max.as_bytes(), b'1'),
+    )
+}
+
+// Dot-separated pre-release identifiers. Numeric identifiers must not have a
+// leading zero.
+fn pre() -> &'static str {
+    r"(?:0|[1-9][0-9]*|[0-9]*[A-Za-z-][0-9A-Za-z-]*)(?:\.(?:0|[1-9][0-9]*|[0-9]*[A-Za-z-][0-9A-Za-z-]*))*"
+}
+
+// Dot-separated build metadata identifiers. Leading zeros are permitted.
+fn build() -> &'static str {
+    r"[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*"
+}
+
+fn version() -> String {
+    let number = number();
+    format!(r"{0}\.{0}\.{0}(?:-{1})?(?:\+{2})?", number, pre(), build())
+}
+
+// A comparator including its operator and the spaces that may follow it, but
+// not leading spaces. The build metadata is parsed and then discarded.
+fn comparator() -> String {
+    let number = number();
+    format!(
+        r"(?:=|>=?|<=?|~|\^)? *{0}(?:\.(?:[*xX](?:\.[*xX])?|{0}(?:\.(?:[*xX]|{0}(?:-{1})?(?:\+{2})?))?))? *",
+        number,
+        pre(),
+        build(),
+    )
+}
+
+fn version_req() -> String {
+    let comparator = comparator();
+    format!(
+        r"^ *(?:[*xX] *|{0}(?:, *{0}){{0,{1}}})$",
+        comparator,
+        parse::MAX_COMPARATORS - 1,
+    )
+}
+
+fn string_schema(description: &str, pattern: &str) -> Schema {
+    json_schema!({
+        "type": "string",
+        "description": description,
+        "pattern": pattern,
+    })
+}
+
+impl JsonSchema for Version {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Version")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("semver::Version")
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        string_schema(
+            "SemVer version, such as 1.0.0-rc.1",
+            &format!("^{}$", version()),
+        )
+    }
+}
+
+impl JsonSchema for VersionReq {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("VersionReq")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("semver::VersionReq")
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        string_schema(
+            "SemVer version requirement, such as >=1.2.3, <1.8.0",
+            &version_req(),
+        )
+    }
+}
+
+impl JsonSchema for Comparator {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Comparator")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("semver::Comparator")
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        string_schema(
+            "SemVer version comparator, such as >=1.2.3",
+            &format!("^ *{}$", comparator()),
+        )
+    }
+}
+
+impl JsonSchema for Prerelease {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("Prerelease")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("semver::Prerelease")
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        string_schema(
+            "SemVer pre-release identifier, such as rc.1, or empty",
+            &format!("^(?:{})?$", pre()),
+        )
+    }
+}
+
+impl JsonSchema for BuildMetadata {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("BuildMetadata")
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("semver::BuildMetadata")
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        string_schema(
+            "SemVer build metadata, such as g1a2b3c, or empty",
+            &format!("^(?:{})?$", build()),
+        )
+    }
+}
@@ -1,8 +1,51 @@
-use crate::{Comparator, Version, VersionReq};
+//! Serde support and `#[serde(with = "...")]` adapters.
+//!
+//! With the `serde` feature enabled, [`Version`], [`VersionReq`],
+//! [`Comparator`], [`Prerelease`] and [`BuildMetadata`] serialize as their
+//! string representation, for example `"1.0.0-rc.1"` or `">=1.2.3, <1.8.0"`,
+//! and [`Op`] as its symbol such as `">="`, with `"*"` for [`Op::Wildcard`].
+//!
+//! The modules in this module are for use with serde's `with` attribute, for
+//! fields whose serialized representation is something other than a string:
+//!
+//! - [`structured`] &mdash; a `Version` as a struct with fields `major`,
+//!   `minor`, `patch`, `pre`, `build`, e.g. `{"major": 1, "minor": 0, "patch":
+//!   0, "pre": "rc.1", "build": ""}`.
+//!
+//! - [`tuple`][mod@tuple] &mdash; a `Version` as a tuple of the same five fields, e.g.
+//!   `[1, 0, 0, "rc.1", ""]`.
+//!
+//! - [`compact`] &mdash; a `Version`, `Comparator` or `VersionReq` as a
+//!   short byte string in formats that are not human readable, such as
+//!   bincode, and as the usual string otherwise.
+//!
+//! - [`comparators`] &mdash; a `VersionReq` as a list of comparator strings,
+//!   e.g. `[">=1.2.3", "<1.8.0"]`.
+//!
+//! - [`lenient`] &mdash; a `Version` or `VersionReq` that may also be written
+//!   as a number or partial version, e.g. `1.0` in TOML or YAML.
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Release {
+//!     #[serde(with = "semver::serde::structured")]
+//!     version: Version,
+//!     #[serde(with = "semver::serde::comparators")]
+//!     rust_version: VersionReq,
+//! }
+//! ```
+
+use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
 use core::fmt;
-use serde::de::{Deserialize, Deserializer, Error, Visitor};
+use serde::de::{Deserialize, Deserializer, Error, Unexpected, Visitor};
 use serde::ser::{Serialize, Serializer};
 
+pub mod compact;
+pub mod comparators;
+pub mod lenient;
+pub mod structured;
+pub mod tuple;
+
 impl Serialize for Version {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -107,3 +150,127 @@ impl<'de> Deserialize<'de> for Comparator {
         deserializer.deserialize_str(ComparatorVisitor)
     }
 }
+
+impl Serialize for Prerelease {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Serialize for BuildMetadata {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Serialize for Op {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match self {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Tilde => "~",
+            Op::Caret => "^",
+            Op::Wildcard => "*",
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Prerelease {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PrereleaseVisitor;
+
+        impl<'de> Visitor<'de> for PrereleaseVisitor {
+            type Value = Prerelease;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("semver pre-release")
+            }
+
+            fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Prerelease::new(string).map_err(Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(PrereleaseVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for BuildMetadata {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BuildMetadataVisitor;
+
+        impl<'de> Visitor<'de> for BuildMetadataVisitor {
+            type Value = BuildMetadata;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("semver build metadata")
+            }
+
+            fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                BuildMetadata::new(string).map_err(Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(BuildMetadataVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for Op {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OpVisitor;
+
+        impl<'de> Visitor<'de> for OpVisitor {
+            type Value = Op;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("semver comparison operator")
+            }
+
+            fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                match string {
+                    "=" => Ok(Op::Exact),
+                    ">" => Ok(Op::Greater),
+                    ">=" => Ok(Op::GreaterEq),
+                    "<" => Ok(Op::Less),
+                    "<=" => Ok(Op::LessEq),
+                    "~" => Ok(Op::Tilde),
+                    "^" => Ok(Op::Caret),
+                    "*" => Ok(Op::Wildcard),
+                    _ => Err(Error::invalid_value(Unexpected::Str(string), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_str(OpVisitor)
+    }
+}
@@ -0,0 +1,411 @@
+//! Serialize a `Version`, `Comparator` or `VersionReq` in a compact binary
+//! format when the serializer is not human readable.
+//!
+//! The ordinary `Serialize` impls always produce strings. With this adapter,
+//! formats that are not [human readable], such as bincode and postcard, get
+//! the bytes described below instead, while human readable formats such as
+//! JSON still get the usual string.
+//!
+//! - **varint**&ensp;&mdash;&ensp;unsigned LEB128: 7 bits per byte, least
+//!   significant group first, with the high bit set on every byte except the
+//!   last. The shortest encoding of the value is required.
+//!
+//! - **string**&ensp;&mdash;&ensp;varint byte length, followed by that many
+//!   ASCII bytes.
+//!
+//! - **Version**&ensp;&mdash;&ensp;varint major, varint minor, varint patch,
+//!   string pre-release, string build metadata.
+//!
+//! - **Comparator**&ensp;&mdash;&ensp;one byte holding the operator in bits
+//!   0&ndash;3 (0&nbsp;`=`, 1&nbsp;`>`, 2&nbsp;`>=`, 3&nbsp;`<`,
+//!   4&nbsp;`<=`, 5&nbsp;`~`, 6&nbsp;`^`, 7&nbsp;wildcard), bit 4 set if the
+//!   minor version is present, and bit 5 set if the patch version is present;
+//!   then varint major, varint minor if present, varint patch if present,
+//!   string pre-release.
+//!
+//! - **VersionReq**&ensp;&mdash;&ensp;varint number of comparators, followed
+//!   by the comparators.
+//!
+//! For example `1.0.0-rc.1` is encoded as `01 00 00 04 72 63 2e 31 00`, and
+//! `>=1.2` as `01 12 01 02 00`.
+//!
+//! # Example
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Package {
+//!     #[serde(with = "semver::serde::compact")]
+//!     version: Version,
+//!     #[serde(with = "semver::serde::compact")]
+//!     rust_version: VersionReq,
+//! }
+//! ```
+//!
+//! The same functions can also be called directly:
+//!
+//! ```
+//! use bincode::Options as _;
+//! use semver::Version;
+//!
+//! let version = Version::parse("1.0.0-rc.1").unwrap();
+//!
+//! let mut bytes = Vec::new();
+//! let mut serializer = bincode::Serializer::new(&mut bytes, bincode::DefaultOptions::new());
+//! semver::serde::compact::serialize(&version, &mut serializer).unwrap();
+//! // Length prefix added by bincode, followed by the compact encoding.
+//! assert_eq!(bytes, b"\x09\x01\x00\x00\x04rc.1\x00");
+//!
+//! let mut deserializer = bincode::Deserializer::from_slice(&bytes, bincode::DefaultOptions::new());
+//! let decoded: Version = semver::serde::compact::deserialize(&mut deserializer).unwrap();
+//! assert_eq!(decoded, version);
+//! ```
+//!
+//! [human readable]: https://docs.rs/serde/1/serde/trait.Serializer.html#method.is_human_readable
+
+use crate::error::ErrorKind;
+use crate::parse::{self, Error as ParseError};
+use crate::{BuildMetadata, Comparator, Op, Prerelease, Version, VersionReq};
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str;
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+/// Types that can be serialized by [`compact::serialize`][serialize].
+///
+/// This trait is sealed and implemented for [`Version`], [`Comparator`] and
+/// [`VersionReq`].
+pub trait Compact: Sized + private::Sealed {}
+
+impl Compact for Version {}
+impl Compact for Comparator {}
+impl Compact for VersionReq {}
+
+mod private {
+    use super::*;
+
+    pub trait Sealed {
+        const EXPECTING: &'static str;
+        fn encode(&self) -> Vec<u8>;
+        fn decode(bytes: &[u8]) -> Result<Self, ParseError>
+        where
+            Self: Sized;
+    }
+
+    impl Sealed for Version {
+        const EXPECTING: &'static str = "compact semver version";
+
+        fn encode(&self) -> Vec<u8> {
+            encode_version(self)
+        }
+
+        fn decode(bytes: &[u8]) -> Result<Self, ParseError> {
+            decode_version(bytes)
+        }
+    }
+
+    impl Sealed for Comparator {
+        const EXPECTING: &'static str = "compact semver comparator";
+
+        fn encode(&self) -> Vec<u8> {
+            encode_comparator(self)
+        }
+
+        fn decode(bytes: &[u8]) -> Result<Self, ParseError> {
+            decode_comparator(bytes)
+        }
+    }
+
+    impl Sealed for VersionReq {
+        const EXPECTING: &'static str = "compact semver version requirement";
+
+        fn encode(&self) -> Vec<u8> {
+            encode_req(self)
+        }
+
+        fn decode(bytes: &[u8]) -> Result<Self, ParseError> {
+            decode_req(bytes)
+        }
+    }
+}
+
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Compact + Serialize,
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        value.serialize(serializer)
+    } else {
+        serializer.serialize_bytes(&value.encode())
+    }
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Compact + Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        T::deserialize(deserializer)
+    } else {
+        deserializer.deserialize_bytes(CompactVisitor {
+            marker: PhantomData,
+        })
+    }
+}
+
+struct CompactVisitor<T> {
+    marker: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for CompactVisitor<T>
+where
+    T: Compact,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(T::EXPECTING)
+    }
+
+    fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        T::decode(bytes).map_err(Error::custom)
+    }
+
+    // Some formats represent bytes as a sequence of u8.
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(256));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+const HAS_MINOR: u8 = 1 << 4;
+const HAS_PATCH: u8 = 1 << 5;
+
+fn encode_version(version: &Version) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(5 + version.pre.len() + version.build.len());
+    push_varint(&mut bytes, version.major);
+    push_varint(&mut bytes, version.minor);
+    push_varint(&mut bytes, version.patch);
+    push_string(&mut bytes, version.pre.as_str());
+    push_string(&mut bytes, version.build.as_str());
+    bytes
+}
+
+fn encode_comparator(comparator: &Comparator) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    push_comparator(&mut bytes, comparator);
+    bytes
+}
+
+fn encode_req(req: &VersionReq) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    push_varint(&mut bytes, req.comparators.len() as u64);
+    for comparator in &req.comparators {
+        push_comparator(&mut bytes, comparator);
+    }
+    bytes
+}
+
+fn decode_version(bytes: &[u8]) -> Result<Version, ParseError> {
+    let mut input = Input { bytes };
+    let version = input.version()?;
+    input.end()?;
+    Ok(version)
+}
+
+fn decode_comparator(bytes: &[u8]) -> Result<Comparator, ParseError> {
+    let mut input = Input { bytes };
+    let comparator = input.comparator()?;
+    input.end()?;
+    Ok(comparator)
+}
+
+fn decode_req(bytes: &[u8]) -> Result<VersionReq, ParseError> {
+    let mut input = Input { bytes };
+    let len = input.varint()?;
+    if len > parse::MAX_COMPARATORS as u64 {
+        return Err(ParseError::new(ErrorKind::ExcessiveComparators));
+    }
+    let mut comparators = Vec::with_capacity(len as usize);
+    for _ in 0..len {
+        comparators.push(input.comparator()?);
+    }
+    input.end()?;
+    Ok(VersionReq { comparators })
+}
+
+fn push_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+fn push_string(bytes: &mut Vec<u8>, string: &str) {
+    push_varint(bytes, string.len() as u64);
+    bytes.extend_from_slice(string.as_bytes());
+}
+
+fn push_comparator(bytes: &mut Vec<u8>, comparator: &Comparator) {
+    let op = match comparator.op {
+        Op::Exact => 0,
+        Op::Greater => 1,
+        Op::GreaterEq => 2,
+        Op::Less => 3,
+        Op::LessEq => 4,
+        Op::Tilde => 5,
+        Op::Caret => 6,
+        Op::Wildcard => 7,
+    };
+    let mut flags = op;
+    if comparator.minor.is_some() {
+        flags |= HAS_MINOR;
+    }
+    if comparator.patch.is_some() {
+        flags |= HAS_PATCH;
+    }
+    bytes.push(flags);
+    push_varint(bytes, comparator.major);
+    if let Some(minor) = comparator.minor {
+        push_varint(bytes, minor);
+    }
+    if let Some(patch) = comparator.patch {
+        push_varint(bytes, patch);
+    }
+    push_string(bytes, comparator.pre.as_str());
+}
+
+fn invalid() -> ParseError {
+    ParseError::new(ErrorKind::InvalidCompactBytes)
+}
+
+struct Input<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+    fn byte(&mut self) -> Result<u8, ParseError> {
+        let (&first, rest) = self.bytes.split_first().ok_or_else(invalid)?;
+        self.bytes = rest;
+        Ok(first)
+    }
+
+    fn end(&self) -> Result<(), ParseError> {
+        if self.bytes.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid())
+        }
+    }
+
+    fn varint(&mut self) -> Result<u64, ParseError> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            let bits = (byte & 0x7F) as u64;
+            if shift == 63 && bits > 1 {
+                return Err(invalid());
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                // Reject a redundant trailing zero group, so that every value
+                // has exactly one encoding.
+                if byte == 0 && shift > 0 {
+                    return Err(invalid());
+                }
+                return Ok(value);
+            }
+            shift += 7;
+            if shift > 63 {
+                return Err(invalid());
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<&'a str, ParseError> {
+        let len = self.varint()?;
+        if len > self.bytes.len() as u64 {
+            return Err(invalid());
+        }
+        let (string, rest) = self.bytes.split_at(len as usize);
+        self.bytes = rest;
+        // Prerelease::new and BuildMetadata::new subsequently reject anything
+        // other than ASCII alphanumerics, hyphen, and dot.
+        str::from_utf8(string).map_err(|_| invalid())
+    }
+
+    fn version(&mut self) -> Result<Version, ParseError> {
+        let major = self.varint()?;
+        let minor = self.varint()?;
+        let patch = self.varint()?;
+        let pre = Prerelease::new(self.string()?)?;
+        let build = BuildMetadata::new(self.string()?)?;
+        Ok(Version {
+            major,
+            minor,
+            patch,
+            pre,
+            build,
+        })
+    }
+
+    fn comparator(&mut self) -> Result<Comparator, ParseError> {
+        let flags = self.byte()?;
+        let op = match flags & 0x0F {
+            0 => Op::Exact,
+            1 => Op::Greater,
+            2 => Op::GreaterEq,
+            3 => Op::Less,
+            4 => Op::LessEq,
+            5 => Op::Tilde,
+            6 => Op::Caret,
+            7 => Op::Wildcard,
+            _ => return Err(invalid()),
+        };
+        if flags & !(0x0F | HAS_MINOR | HAS_PATCH) != 0 {
+            return Err(invalid());
+        }
+        let major = self.varint()?;
+        let minor = if flags & HAS_MINOR != 0 {
+            Some(self.varint()?)
+        } else {
+            None
+        };
+        let patch = if flags & HAS_PATCH != 0 {
+            Some(self.varint()?)
+        } else {
+            None
+        };
+        let pre = Prerelease::new(self.string()?)?;
+
+        // Reject comparators that the parser would never produce.
+        if patch.is_some() && minor.is_none()
+            || !pre.is_empty() && patch.is_none()
+            || op == Op::Wildcard && patch.is_some()
+        {
+            return Err(invalid());
+        }
+
+        Ok(Comparator {
+            op,
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+}
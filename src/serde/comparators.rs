@@ -0,0 +1,111 @@
+//! Serialize a `VersionReq` as a list of comparator strings.
+//!
+//! An empty list is the requirement `*`, which matches any version that is not
+//! a pre-release.
+//!
+//! # Example
+//!
+//! ```
+//! use semver::VersionReq;
+//!
+//! let json = r#"[">=1.2.3","<1.8.0"]"#;
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str(json);
+//! let req = semver::serde::comparators::deserialize(&mut deserializer).unwrap();
+//! assert_eq!(req, VersionReq::parse(">=1.2.3, <1.8.0").unwrap());
+//!
+//! let mut out = Vec::new();
+//! let mut serializer = serde_json::Serializer::new(&mut out);
+//! semver::serde::comparators::serialize(&req, &mut serializer).unwrap();
+//! assert_eq!(out, json.as_bytes());
+//! ```
+
+use crate::error::ErrorKind;
+use crate::parse::{self, Error as ParseError};
+use crate::{Comparator, VersionReq};
+use alloc::vec::Vec;
+use core::fmt;
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+
+pub fn serialize<S>(req: &VersionReq, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(req.comparators.len()))?;
+    for comparator in &req.comparators {
+        seq.serialize_element(&ComparatorStr(comparator))?;
+    }
+    seq.end()
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<VersionReq, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(SeqVisitor)
+}
+
+// Comparators in string form regardless of whether the format is human
+// readable.
+struct ComparatorStr<T>(T);
+
+impl Serialize for ComparatorStr<&Comparator> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for ComparatorStr<Comparator> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ComparatorVisitor;
+
+        impl<'de> Visitor<'de> for ComparatorVisitor {
+            type Value = ComparatorStr<Comparator>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("semver comparator")
+            }
+
+            fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                string.parse().map(ComparatorStr).map_err(Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ComparatorVisitor)
+    }
+}
+
+struct SeqVisitor;
+
+impl<'de> Visitor<'de> for SeqVisitor {
+    type Value = VersionReq;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("list of semver comparators")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut comparators = Vec::new();
+        while let Some(ComparatorStr(comparator)) = seq.next_element()? {
+            if comparators.len() == parse::MAX_COMPARATORS {
+                let error = ParseError::new(ErrorKind::ExcessiveComparators);
+                return Err(Error::custom(error));
+            }
+            comparators.push(comparator);
+        }
+        Ok(VersionReq { comparators })
+    }
+}
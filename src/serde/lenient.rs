@@ -0,0 +1,244 @@
+//! Deserialize a `Version` or `VersionReq` from a number or partial version.
+//!
+//! Configuration formats like TOML and YAML turn an unquoted `1.0` into a
+//! floating point number, and `2` into an integer, which the ordinary
+//! `Deserialize` impls reject. With this adapter, the following are accepted
+//! in addition to strings in the usual format:
+//!
+//! - an integer `N` is the version `N.0.0`, or the requirement `^N`;
+//!
+//! - a float `N.0` is the version `N.0.0`, or the requirement `^N.0`;
+//!
+//! - a string `"N"` or `"N.M"` is the version `N.0.0` or `N.M.0`.
+//!
+//! Serialization always produces the string form.
+//!
+//! # Floats with a fractional part
+//!
+//! A float does not remember how it was written. In YAML and TOML, `1.10` and
+//! `1.1` are the same number, so there is no telling whether an unquoted
+//! `rust-version = 1.70` meant 1.70 or 1.7. Rather than guess, floats other
+//! than `N.0` are rejected with an error asking for the version to be quoted:
+//! `"1.70"`.
+//!
+//! The [`floats`] adapter accepts them anyway, reading the float as the
+//! shortest decimal that represents it, so that `1.70` becomes 1.7. Only use
+//! it where the data is known not to have minor versions ending in 0.
+//!
+//! Floats whose shortest representation has more significant digits than an
+//! `f64` reliably preserves, such as `1.23456789012345678`, are rejected with
+//! an error instead of being deserialized from a rounded value.
+//!
+//! # Example
+//!
+//! ```
+//! use semver::{Version, VersionReq};
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str("1.0");
+//! let version: Version = semver::serde::lenient::deserialize(&mut deserializer).unwrap();
+//! assert_eq!(version, Version::new(1, 0, 0));
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str("1.70");
+//! let error = semver::serde::lenient::deserialize::<VersionReq, _>(&mut deserializer).unwrap_err();
+//! assert!(error.to_string().starts_with("floating point number 1.7 is ambiguous"));
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str(r#""1.70""#);
+//! let req: VersionReq = semver::serde::lenient::deserialize(&mut deserializer).unwrap();
+//! assert_eq!(req, VersionReq::parse("^1.70").unwrap());
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str("2");
+//! let version: Version = semver::serde::lenient::deserialize(&mut deserializer).unwrap();
+//! assert_eq!(version, Version::new(2, 0, 0));
+//! ```
+
+use crate::parse::Error as ParseError;
+use crate::{Comparator, Op, Version, VersionReq};
+use alloc::string::ToString;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{Deserializer, Error, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+/// Types that can be deserialized by [`lenient::deserialize`][deserialize].
+///
+/// This trait is sealed and implemented for [`Version`] and [`VersionReq`].
+pub trait Lenient: Sized + private::Sealed {}
+
+impl Lenient for Version {}
+impl Lenient for VersionReq {}
+
+mod private {
+    use super::*;
+
+    pub trait Sealed {
+        const EXPECTING: &'static str;
+        fn parse_lenient(text: &str) -> Result<Self, ParseError>
+        where
+            Self: Sized;
+    }
+
+    impl Sealed for Version {
+        const EXPECTING: &'static str = "semver version or number";
+
+        fn parse_lenient(text: &str) -> Result<Self, ParseError> {
+            let error = match Version::parse(text) {
+                Ok(version) => return Ok(version),
+                Err(error) => error,
+            };
+            // Accept "N" and "N.M", but not any operator, wildcard, or
+            // pre-release that would also be accepted as a comparator.
+            match Comparator::parse(text) {
+                Ok(cmp)
+                    if cmp.op == Op::Caret
+                        && cmp.patch.is_none()
+                        && text
+                            .trim_start()
+                            .starts_with(|ch: char| ch.is_ascii_digit()) =>
+                {
+                    Ok(Version::new(cmp.major, cmp.minor.unwrap_or(0), 0))
+                }
+                _ => Err(error),
+            }
+        }
+    }
+
+    impl Sealed for VersionReq {
+        const EXPECTING: &'static str = "semver version requirement or number";
+
+        fn parse_lenient(text: &str) -> Result<Self, ParseError> {
+            VersionReq::parse(text)
+        }
+    }
+}
+
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Lenient + Serialize,
+    S: Serializer,
+{
+    value.serialize(serializer)
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Lenient,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(LenientVisitor {
+        floats: false,
+        marker: PhantomData,
+    })
+}
+
+/// Like [`lenient`][super], but accepting floats with a fractional part too.
+///
+/// A float is read as the shortest decimal that represents it, so both `1.7`
+/// and `1.70` become version 1.7.0 or requirement ^1.7. See [Floats with a
+/// fractional part][super#floats-with-a-fractional-part].
+pub mod floats {
+    use super::{Lenient, LenientVisitor};
+    use core::marker::PhantomData;
+    use serde::de::Deserializer;
+    use serde::ser::{Serialize, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Lenient + Serialize,
+        S: Serializer,
+    {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Lenient,
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(LenientVisitor {
+            floats: true,
+            marker: PhantomData,
+        })
+    }
+}
+
+struct LenientVisitor<T> {
+    // Whether to accept floats with a fractional part.
+    floats: bool,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for LenientVisitor<T>
+where
+    T: Lenient,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(T::EXPECTING)
+    }
+
+    fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        T::parse_lenient(string).map_err(Error::custom)
+    }
+
+    fn visit_u64<E>(self, n: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.visit_str(&n.to_string())
+    }
+
+    fn visit_i64<E>(self, n: i64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        match u64::try_from(n) {
+            Ok(n) => self.visit_u64(n),
+            Err(_) => Err(Error::invalid_value(Unexpected::Signed(n), &self)),
+        }
+    }
+
+    fn visit_f64<E>(self, n: f64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        if !n.is_finite() || n < 0.0 {
+            return Err(Error::invalid_value(Unexpected::Float(n), &self));
+        }
+
+        // Display for f64 produces the shortest representation that round
+        // trips, never in exponential notation. If that has more significant
+        // digits than an f64 can faithfully hold, the number written in the
+        // source was not necessarily the one we received. Trailing zeros of a
+        // whole number are not significant, but from 2^53 on not every whole
+        // number is representable, so those are equally suspect.
+        let mut repr = n.to_string();
+        let digits = repr.trim_end_matches('0').trim_end_matches('.');
+        let significant = digits
+            .bytes()
+            .filter(u8::is_ascii_digit)
+            .skip_while(|&digit| digit == b'0')
+            .count();
+        if significant > f64::DIGITS as usize || n >= 9_007_199_254_740_992.0 {
+            return Err(Error::custom(format_args!(
+                "floating point number {} may have lost precision, write the version as a string instead",
+                repr,
+            )));
+        }
+
+        if !repr.contains('.') {
+            // Written as N.0 or N.00, both of which are minor version 0.
+            repr.push_str(".0");
+        } else if !self.floats {
+            return Err(Error::custom(format_args!(
+                "floating point number {} is ambiguous, it may have been written with trailing zeros such as {}0; write the version as a string instead",
+                repr, repr,
+            )));
+        }
+
+        self.visit_str(&repr)
+    }
+}
@@ -0,0 +1,146 @@
+//! Serialize a `Version` as a struct of its five fields.
+//!
+//! The pre-release and build metadata are serialized as strings, which are
+//! empty if absent. When deserializing, `pre` and `build` may be omitted.
+//!
+//! # Example
+//!
+//! ```
+//! use semver::Version;
+//!
+//! let json = r#"{"major":1,"minor":0,"patch":0,"pre":"rc.1","build":""}"#;
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str(json);
+//! let version = semver::serde::structured::deserialize(&mut deserializer).unwrap();
+//! assert_eq!(version, Version::parse("1.0.0-rc.1").unwrap());
+//!
+//! let mut out = Vec::new();
+//! let mut serializer = serde_json::Serializer::new(&mut out);
+//! semver::serde::structured::serialize(&version, &mut serializer).unwrap();
+//! assert_eq!(out, json.as_bytes());
+//! ```
+
+use crate::{BuildMetadata, Prerelease, Version};
+use core::fmt;
+use serde::de::{Deserialize, Deserializer, Error, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeStruct, Serializer};
+
+const FIELDS: &[&str] = &["major", "minor", "patch", "pre", "build"];
+
+pub fn serialize<S>(version: &Version, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut state = serializer.serialize_struct("Version", 5)?;
+    state.serialize_field("major", &version.major)?;
+    state.serialize_field("minor", &version.minor)?;
+    state.serialize_field("patch", &version.patch)?;
+    state.serialize_field("pre", &version.pre)?;
+    state.serialize_field("build", &version.build)?;
+    state.end()
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Version, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct("Version", FIELDS, StructVisitor)
+}
+
+enum Field {
+    Major,
+    Minor,
+    Patch,
+    Pre,
+    Build,
+}
+
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FieldVisitor;
+
+        impl<'de> Visitor<'de> for FieldVisitor {
+            type Value = Field;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("field identifier")
+            }
+
+            fn visit_str<E>(self, string: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                match string {
+                    "major" => Ok(Field::Major),
+                    "minor" => Ok(Field::Minor),
+                    "patch" => Ok(Field::Patch),
+                    "pre" => Ok(Field::Pre),
+                    "build" => Ok(Field::Build),
+                    _ => Err(Error::unknown_field(string, FIELDS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(FieldVisitor)
+    }
+}
+
+struct StructVisitor;
+
+impl<'de> Visitor<'de> for StructVisitor {
+    type Value = Version;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("semver version struct")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut major = None;
+        let mut minor = None;
+        let mut patch = None;
+        let mut pre = None;
+        let mut build = None;
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Major => set(&mut major, "major", map.next_value()?)?,
+                Field::Minor => set(&mut minor, "minor", map.next_value()?)?,
+                Field::Patch => set(&mut patch, "patch", map.next_value()?)?,
+                Field::Pre => set(&mut pre, "pre", map.next_value()?)?,
+                Field::Build => set(&mut build, "build", map.next_value()?)?,
+            }
+        }
+        Ok(Version {
+            major: major.ok_or_else(|| Error::missing_field("major"))?,
+            minor: minor.ok_or_else(|| Error::missing_field("minor"))?,
+            patch: patch.ok_or_else(|| Error::missing_field("patch"))?,
+            pre: pre.unwrap_or(Prerelease::EMPTY),
+            build: build.unwrap_or(BuildMetadata::EMPTY),
+        })
+    }
+
+    // Formats that are not self-describing represent structs as a sequence of
+    // the field values.
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        super::tuple::visit_seq(seq, &self)
+    }
+}
+
+fn set<T, E>(slot: &mut Option<T>, field: &'static str, value: T) -> Result<(), E>
+where
+    E: Error,
+{
+    if slot.is_some() {
+        return Err(Error::duplicate_field(field));
+    }
+    *slot = Some(value);
+    Ok(())
+}
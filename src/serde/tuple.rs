@@ -0,0 +1,92 @@
+//! Serialize a `Version` as a tuple `(major, minor, patch, pre, build)`.
+//!
+//! The pre-release and build metadata are serialized as strings, which are
+//! empty if absent. When deserializing from a self-describing format, a
+//! sequence of only the first three or four elements is also accepted.
+//!
+//! # Example
+//!
+//! ```
+//! use semver::Version;
+//!
+//! let json = r#"[1,0,0,"rc.1",""]"#;
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str(json);
+//! let version = semver::serde::tuple::deserialize(&mut deserializer).unwrap();
+//! assert_eq!(version, Version::parse("1.0.0-rc.1").unwrap());
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str("[1,2,3]");
+//! let version = semver::serde::tuple::deserialize(&mut deserializer).unwrap();
+//! assert_eq!(version, Version::new(1, 2, 3));
+//!
+//! let mut out = Vec::new();
+//! let mut serializer = serde_json::Serializer::new(&mut out);
+//! semver::serde::tuple::serialize(&Version::new(1, 2, 3), &mut serializer).unwrap();
+//! assert_eq!(out, br#"[1,2,3,"",""]"#);
+//! ```
+
+use crate::{BuildMetadata, Prerelease, Version};
+use core::fmt;
+use serde::de::{Deserializer, Error, Expected, SeqAccess, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+
+pub fn serialize<S>(version: &Version, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut state = serializer.serialize_tuple(5)?;
+    state.serialize_element(&version.major)?;
+    state.serialize_element(&version.minor)?;
+    state.serialize_element(&version.patch)?;
+    state.serialize_element(&version.pre)?;
+    state.serialize_element(&version.build)?;
+    state.end()
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Version, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_tuple(5, TupleVisitor)
+}
+
+struct TupleVisitor;
+
+impl<'de> Visitor<'de> for TupleVisitor {
+    type Value = Version;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("semver version tuple")
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        visit_seq(seq, &self)
+    }
+}
+
+pub(super) fn visit_seq<'de, A>(mut seq: A, expected: &dyn Expected) -> Result<Version, A::Error>
+where
+    A: SeqAccess<'de>,
+{
+    let major = seq
+        .next_element()?
+        .ok_or_else(|| Error::invalid_length(0, expected))?;
+    let minor = seq
+        .next_element()?
+        .ok_or_else(|| Error::invalid_length(1, expected))?;
+    let patch = seq
+        .next_element()?
+        .ok_or_else(|| Error::invalid_length(2, expected))?;
+    let pre = seq.next_element()?.unwrap_or(Prerelease::EMPTY);
+    let build = seq.next_element()?.unwrap_or(BuildMetadata::EMPTY);
+    Ok(Version {
+        major,
+        minor,
+        patch,
+        pre,
+        build,
+    })
+}
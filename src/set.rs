@@ -0,0 +1,200 @@
+use crate::interval;
+use crate::{Version, VersionReq};
+use alloc::collections::btree_set::{self, BTreeSet};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::Bound;
+
+/// Sorted collection of versions, such as the published versions of a crate.
+///
+/// Versions are kept in the order of [`Ord for Version`][Version#impl-Ord-for-Version],
+/// in a B-tree. Insertion, removal, and finding the neighbors of a version
+/// take time logarithmic in the size of the set.
+///
+/// # Example
+///
+/// ```
+/// use semver::{Version, VersionReq, VersionSet};
+///
+/// let published: VersionSet = ["0.9.1", "1.0.0", "1.2.0", "1.3.0-rc.1", "2.0.0-beta.1"]
+///     .iter()
+///     .map(|v| Version::parse(v).unwrap())
+///     .collect();
+///
+/// let req = VersionReq::parse("^1.2").unwrap();
+/// let matching: Vec<_> = published.matching(&req).map(Version::to_string).collect();
+/// assert_eq!(matching, ["1.2.0"]);
+///
+/// assert_eq!(published.latest(false).unwrap().to_string(), "1.2.0");
+/// assert_eq!(published.latest(true).unwrap().to_string(), "2.0.0-beta.1");
+///
+/// let after = published.successor(&Version::parse("1.0.0").unwrap());
+/// assert_eq!(after.unwrap().to_string(), "1.2.0");
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct VersionSet {
+    versions: BTreeSet<Version>,
+}
+
+impl VersionSet {
+    /// Create an empty set.
+    pub const fn new() -> Self {
+        VersionSet {
+            versions: BTreeSet::new(),
+        }
+    }
+
+    /// Add a version to the set, returning whether it was not already
+    /// present.
+    pub fn insert(&mut self, version: Version) -> bool {
+        self.versions.insert(version)
+    }
+
+    /// Remove a version from the set, returning whether it was present.
+    pub fn remove(&mut self, version: &Version) -> bool {
+        self.versions.remove(version)
+    }
+
+    /// Whether the set contains exactly this version, including its build
+    /// metadata.
+    pub fn contains(&self, version: &Version) -> bool {
+        self.versions.contains(version)
+    }
+
+    /// Number of versions in the set.
+    pub fn len(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Whether the set contains no versions.
+    pub fn is_empty(&self) -> bool {
+        self.versions.is_empty()
+    }
+
+    /// Iterate over the versions in ascending order.
+    pub fn iter(&self) -> btree_set::Iter<'_, Version> {
+        self.versions.iter()
+    }
+
+    /// Iterate in ascending order over the versions that match the given
+    /// requirement.
+    ///
+    /// Only the part of the set between the lowest and highest version that
+    /// the requirement could match is visited.
+    pub fn matching<'a>(&'a self, req: &VersionReq) -> impl Iterator<Item = &'a Version> + 'a {
+        let compiled = req.compile();
+        // A version without build metadata orders before every other version
+        // of the same precedence, and the bounds never have build metadata,
+        // so the lower bound can start the range as is. Past the upper bound,
+        // every later version is out of range too.
+        let lower = compiled.lower.clone();
+        let upper = compiled.upper.clone();
+        self.versions
+            .range((lower, Bound::Unbounded))
+            .take_while(move |version| interval::is_below(&upper, version))
+            .filter(move |version| compiled.matches(version))
+    }
+
+    /// The highest version in the set, optionally skipping pre-releases.
+    pub fn latest(&self, include_prerelease: bool) -> Option<&Version> {
+        self.versions
+            .iter()
+            .rev()
+            .find(|version| include_prerelease || version.pre.is_empty())
+    }
+
+    /// The latest version in each group of versions that are compatible
+    /// according to Cargo's rules, in ascending order.
+    ///
+    /// The groups are all versions with the same major version if it is
+    /// nonzero; otherwise the same minor version if that is nonzero;
+    /// otherwise the same patch version. So `1.2.0` and `1.5.3` are in one
+    /// group, and `0.2.0`, `0.3.0` and `0.0.4` in three groups of their own,
+    /// the same way that `^1.2.0` matches `1.5.3` but `^0.2.0` does not
+    /// match `0.3.0`.
+    ///
+    /// Within each group, the latest version that is not a pre-release is
+    /// chosen, or the latest pre-release if the group only has
+    /// pre-releases.
+    pub fn latest_per_compat_bucket(&self) -> Vec<&Version> {
+        let mut latest: Vec<((u64, u64, u64), &Version)> = Vec::new();
+        for version in self.versions.iter().rev() {
+            let bucket = compat_bucket(version);
+            match latest.last_mut() {
+                Some((last_bucket, last)) if *last_bucket == bucket => {
+                    if version.pre.is_empty() && !last.pre.is_empty() {
+                        *last = version;
+                    }
+                }
+                _ => latest.push((bucket, version)),
+            }
+        }
+        latest
+            .into_iter()
+            .rev()
+            .map(|(_bucket, version)| version)
+            .collect()
+    }
+
+    /// The highest version in the set that is lower than the given one.
+    pub fn predecessor(&self, version: &Version) -> Option<&Version> {
+        self.versions
+            .range((Bound::Unbounded, Bound::Excluded(version)))
+            .next_back()
+    }
+
+    /// The lowest version in the set that is higher than the given one.
+    pub fn successor(&self, version: &Version) -> Option<&Version> {
+        self.versions
+            .range((Bound::Excluded(version), Bound::Unbounded))
+            .next()
+    }
+}
+
+// Versions with the same leftmost nonzero component among major, minor and
+// patch, and equal components up to there, are compatible with each other.
+fn compat_bucket(version: &Version) -> (u64, u64, u64) {
+    match (version.major, version.minor) {
+        (0, 0) => (0, 0, version.patch),
+        (0, minor) => (0, minor, 0),
+        (major, _) => (major, 0, 0),
+    }
+}
+
+impl FromIterator<Version> for VersionSet {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Version>,
+    {
+        VersionSet {
+            versions: BTreeSet::from_iter(iter),
+        }
+    }
+}
+
+impl Extend<Version> for VersionSet {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = Version>,
+    {
+        self.versions.extend(iter);
+    }
+}
+
+impl IntoIterator for VersionSet {
+    type Item = Version;
+    type IntoIter = btree_set::IntoIter<Version>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.versions.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a VersionSet {
+    type Item = &'a Version;
+    type IntoIter = btree_set::Iter<'a, Version>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.versions.iter()
+    }
+}
@@ -0,0 +1,256 @@
+// Order-preserving byte encoding of Version.
+//
+// The encoding is a concatenation of self-delimiting fields, so that comparing
+// two encodings byte by byte compares the first field that differs.
+//
+//   number     one byte holding the count of significant bytes (0 through 8),
+//              followed by that many bytes of the number in big endian; longer
+//              means larger, and same length compares by value
+//
+//   version    major number, minor number, patch number, pre-release, build
+//              metadata
+//
+//   pre        0x02 if the pre-release is empty, which sorts after
+//              0x01 followed by a list of identifiers
+//
+//   build      list of identifiers; build metadata is omitted entirely in the
+//              precedence-only variant of the encoding
+//
+//   list       the identifiers followed by 0x00, so that a list that is a
+//              prefix of another list is the smaller one
+//
+//   identifier 0x01 followed by a numeric identifier, which sorts before
+//              0x02 followed by the ASCII bytes of an alphanumeric identifier
+//              and a 0x00 terminator (identifiers never contain \0)
+//
+// Numeric pre-release identifiers are compared by the number of digits, then by
+// the digits, and are encoded as a number for the length followed by the
+// digits. Numeric build metadata identifiers are compared by the number of
+// digits after leading zeros, then by the digits, then by the number of leading
+// zeros, and are encoded as a number for the length without leading zeros, the
+// digits without leading zeros, a number for the count of leading zeros, and
+// the leading zeros.
+
+use crate::error::ErrorKind;
+use crate::parse::Error;
+use crate::{BuildMetadata, Prerelease, Version};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str;
+
+const END: u8 = 0x00;
+const NUMERIC: u8 = 0x01;
+const ALPHANUMERIC: u8 = 0x02;
+const HAS_PRE: u8 = 0x01;
+const NO_PRE: u8 = 0x02;
+
+pub(crate) fn encode(version: &Version, include_build: bool) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(16 + version.pre.len() + version.build.len());
+    push_number(&mut bytes, version.major);
+    push_number(&mut bytes, version.minor);
+    push_number(&mut bytes, version.patch);
+    if version.pre.is_empty() {
+        bytes.push(NO_PRE);
+    } else {
+        bytes.push(HAS_PRE);
+        for identifier in version.pre.split('.') {
+            if is_numeric(identifier) {
+                bytes.push(NUMERIC);
+                push_number(&mut bytes, identifier.len() as u64);
+                bytes.extend_from_slice(identifier.as_bytes());
+            } else {
+                push_alphanumeric(&mut bytes, identifier);
+            }
+        }
+        bytes.push(END);
+    }
+    if include_build {
+        if !version.build.is_empty() {
+            for identifier in version.build.split('.') {
+                if is_numeric(identifier) {
+                    let value = identifier.trim_start_matches('0');
+                    let zeros = &identifier[..identifier.len() - value.len()];
+                    bytes.push(NUMERIC);
+                    push_number(&mut bytes, value.len() as u64);
+                    bytes.extend_from_slice(value.as_bytes());
+                    push_number(&mut bytes, zeros.len() as u64);
+                    bytes.extend_from_slice(zeros.as_bytes());
+                } else {
+                    push_alphanumeric(&mut bytes, identifier);
+                }
+            }
+        }
+        bytes.push(END);
+    }
+    bytes
+}
+
+pub(crate) fn decode(bytes: &[u8]) -> Result<Version, Error> {
+    let mut input = Input { bytes };
+    let major = input.number()?;
+    let minor = input.number()?;
+    let patch = input.number()?;
+
+    let pre = match input.byte()? {
+        NO_PRE => Prerelease::EMPTY,
+        HAS_PRE => {
+            let mut string = String::new();
+            while let Some(tag) = input.identifier_tag()? {
+                if !string.is_empty() {
+                    string.push('.');
+                }
+                if tag == NUMERIC {
+                    let len = input.number()?;
+                    string.push_str(input.digits(len)?);
+                } else {
+                    string.push_str(input.alphanumeric()?);
+                }
+            }
+            if string.is_empty() {
+                return Err(invalid());
+            }
+            Prerelease::new(&string)?
+        }
+        _ => return Err(invalid()),
+    };
+
+    // The build metadata is absent from the precedence-only encoding.
+    let include_build = !input.bytes.is_empty();
+    let build = if include_build {
+        let mut string = String::new();
+        while let Some(tag) = input.identifier_tag()? {
+            if !string.is_empty() {
+                string.push('.');
+            }
+            if tag == NUMERIC {
+                let len = input.number()?;
+                let value = input.digits(len)?;
+                let len = input.number()?;
+                let zeros = input.digits(len)?;
+                if value.starts_with('0') || zeros.bytes().any(|b| b != b'0') {
+                    return Err(invalid());
+                }
+                string.push_str(zeros);
+                string.push_str(value);
+            } else {
+                string.push_str(input.alphanumeric()?);
+            }
+        }
+        if string.is_empty() {
+            BuildMetadata::EMPTY
+        } else {
+            BuildMetadata::new(&string)?
+        }
+    } else {
+        BuildMetadata::EMPTY
+    };
+
+    if !input.bytes.is_empty() {
+        return Err(invalid());
+    }
+
+    let version = Version {
+        major,
+        minor,
+        patch,
+        pre,
+        build,
+    };
+
+    // The checks above leave room for bytes that decode to a version without
+    // being its encoding, such as an alphanumeric identifier containing a dot,
+    // which encodes as two identifiers.
+    if encode(&version, include_build) != bytes {
+        return Err(invalid());
+    }
+
+    Ok(version)
+}
+
+fn push_number(bytes: &mut Vec<u8>, number: u64) {
+    let len = 8 - number.leading_zeros() as usize / 8;
+    bytes.push(len as u8);
+    bytes.extend_from_slice(&number.to_be_bytes()[8 - len..]);
+}
+
+fn push_alphanumeric(bytes: &mut Vec<u8>, identifier: &str) {
+    bytes.push(ALPHANUMERIC);
+    bytes.extend_from_slice(identifier.as_bytes());
+    bytes.push(END);
+}
+
+fn is_numeric(identifier: &str) -> bool {
+    identifier.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn invalid() -> Error {
+    Error::new(ErrorKind::InvalidSortableBytes)
+}
+
+struct Input<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+    fn byte(&mut self) -> Result<u8, Error> {
+        let (&first, rest) = self.bytes.split_first().ok_or_else(invalid)?;
+        self.bytes = rest;
+        Ok(first)
+    }
+
+    fn take(&mut self, len: u64) -> Result<&'a [u8], Error> {
+        if len > self.bytes.len() as u64 {
+            return Err(invalid());
+        }
+        let (taken, rest) = self.bytes.split_at(len as usize);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn number(&mut self) -> Result<u64, Error> {
+        let len = self.byte()?;
+        if len > 8 {
+            return Err(invalid());
+        }
+        let digits = self.take(len as u64)?;
+        if digits.first() == Some(&0) {
+            return Err(invalid());
+        }
+        let mut be_bytes = [0u8; 8];
+        be_bytes[8 - digits.len()..].copy_from_slice(digits);
+        Ok(u64::from_be_bytes(be_bytes))
+    }
+
+    fn identifier_tag(&mut self) -> Result<Option<u8>, Error> {
+        match self.byte()? {
+            END => Ok(None),
+            tag @ (NUMERIC | ALPHANUMERIC) => Ok(Some(tag)),
+            _ => Err(invalid()),
+        }
+    }
+
+    fn digits(&mut self, len: u64) -> Result<&'a str, Error> {
+        let digits = self.take(len)?;
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return Err(invalid());
+        }
+        // SAFETY: all ASCII digits.
+        Ok(unsafe { str::from_utf8_unchecked(digits) })
+    }
+
+    fn alphanumeric(&mut self) -> Result<&'a str, Error> {
+        let len = self
+            .bytes
+            .iter()
+            .position(|&b| b == END)
+            .ok_or_else(invalid)?;
+        let identifier = self.take(len as u64)?;
+        self.bytes = &self.bytes[1..];
+        if is_numeric(str::from_utf8(identifier).map_err(|_| invalid())?) {
+            return Err(invalid());
+        }
+        // Prerelease::new and BuildMetadata::new subsequently reject anything
+        // other than ASCII alphanumerics and hyphen.
+        Ok(unsafe { str::from_utf8_unchecked(identifier) })
+    }
+}
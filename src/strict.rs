@@ -0,0 +1,191 @@
+use crate::error::Position;
+use crate::Version;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// Every way in which a string departs from the semver.org grammar, as
+/// returned by [`Version::parse_strict`].
+pub struct StrictError {
+    pub(crate) violations: Vec<StrictViolation>,
+}
+
+/// One departure of a string from the semver.org grammar.
+pub struct StrictViolation {
+    pub(crate) span: Range<usize>,
+    pub(crate) kind: ViolationKind,
+}
+
+#[derive(Copy, Clone)]
+pub(crate) enum ViolationKind {
+    Empty,
+    CoreParts(usize),
+    EmptyNumber(Position),
+    NonDigit(Position, char),
+    LeadingZero(Position),
+    Overflow(Position),
+    EmptySegment(Position),
+    IllegalCharacter(Position, char),
+}
+
+impl StrictError {
+    /// The violations in the order they occur in the input. Never empty.
+    pub fn violations(&self) -> &[StrictViolation] {
+        &self.violations
+    }
+}
+
+impl StrictViolation {
+    /// Byte range of the input that the violation is about.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Whether this is a number that the semver.org grammar allows, but that
+    /// is too large for this crate to represent as `u64`. This is the only
+    /// kind of violation that is not a departure from the grammar.
+    pub fn exceeds_u64(&self) -> bool {
+        matches!(self.kind, ViolationKind::Overflow(_))
+    }
+}
+
+pub(crate) fn parse(text: &str) -> Result<Version, StrictError> {
+    let violations = validate(text);
+    if !violations.is_empty() {
+        return Err(StrictError { violations });
+    }
+    match Version::parse(text) {
+        Ok(version) => Ok(version),
+        Err(_) => unreachable!(),
+    }
+}
+
+// <valid semver> ::= <version core>
+//                  | <version core> "-" <pre-release>
+//                  | <version core> "+" <build>
+//                  | <version core> "-" <pre-release> "+" <build>
+//
+// The pre-release cannot contain '+', and the version core cannot contain
+// '-', so the first of each marks where the next part begins. Each part is
+// then checked in full, rather than stopping at the first problem.
+fn validate(text: &str) -> Vec<StrictViolation> {
+    let mut violations = Vec::new();
+    if text.is_empty() {
+        violations.push(StrictViolation {
+            span: 0..0,
+            kind: ViolationKind::Empty,
+        });
+        return violations;
+    }
+
+    let (rest, build) = match text.find('+') {
+        Some(i) => (&text[..i], Some(i + 1)),
+        None => (text, None),
+    };
+    let (core, pre) = match rest.find('-') {
+        Some(i) => (&rest[..i], Some(i + 1)),
+        None => (rest, None),
+    };
+
+    let parts = core.split('.').count();
+    if parts != 3 {
+        violations.push(StrictViolation {
+            span: 0..core.len(),
+            kind: ViolationKind::CoreParts(parts),
+        });
+    }
+    let mut offset = 0;
+    for (number, pos) in core
+        .split('.')
+        .zip([Position::Major, Position::Minor, Position::Patch])
+    {
+        numeric(number, offset, pos, &mut violations);
+        offset += number.len() + 1;
+    }
+
+    if let Some(start) = pre {
+        identifiers(&rest[start..], start, Position::Pre, &mut violations);
+    }
+    if let Some(start) = build {
+        identifiers(&text[start..], start, Position::Build, &mut violations);
+    }
+
+    violations
+}
+
+// <numeric identifier> ::= "0" | <positive digit> | <positive digit> <digits>
+fn numeric(number: &str, offset: usize, pos: Position, violations: &mut Vec<StrictViolation>) {
+    let span = offset..offset + number.len();
+    if number.is_empty() {
+        violations.push(StrictViolation {
+            span,
+            kind: ViolationKind::EmptyNumber(pos),
+        });
+        return;
+    }
+    let mut all_digits = true;
+    for (i, ch) in number.char_indices() {
+        if !ch.is_ascii_digit() {
+            all_digits = false;
+            violations.push(StrictViolation {
+                span: offset + i..offset + i + ch.len_utf8(),
+                kind: ViolationKind::NonDigit(pos, ch),
+            });
+        }
+    }
+    if !all_digits {
+        return;
+    }
+    if number.len() > 1 && number.starts_with('0') {
+        violations.push(StrictViolation {
+            span,
+            kind: ViolationKind::LeadingZero(pos),
+        });
+    } else if number.parse::<u64>().is_err() {
+        violations.push(StrictViolation {
+            span,
+            kind: ViolationKind::Overflow(pos),
+        });
+    }
+}
+
+// <dot-separated pre-release identifiers> ::= <pre-release identifier>
+//     | <pre-release identifier> "." <dot-separated pre-release identifiers>
+//
+// <pre-release identifier> ::= <alphanumeric identifier>
+//                            | <numeric identifier>
+//
+// <build identifier> ::= <alphanumeric identifier> | <digits>
+//
+// Only pre-release identifiers are subject to the leading zero rule of
+// numeric identifiers. Neither kind has a size limit, because the numeric
+// ones are compared digit by digit rather than as u64.
+fn identifiers(text: &str, offset: usize, pos: Position, violations: &mut Vec<StrictViolation>) {
+    let mut start = offset;
+    for identifier in text.split('.') {
+        let span = start..start + identifier.len();
+        if identifier.is_empty() {
+            violations.push(StrictViolation {
+                span: span.clone(),
+                kind: ViolationKind::EmptySegment(pos),
+            });
+        }
+        let mut all_digits = true;
+        for (i, ch) in identifier.char_indices() {
+            if !ch.is_ascii_alphanumeric() && ch != '-' {
+                violations.push(StrictViolation {
+                    span: start + i..start + i + ch.len_utf8(),
+                    kind: ViolationKind::IllegalCharacter(pos, ch),
+                });
+            }
+            all_digits &= ch.is_ascii_digit();
+        }
+        if pos == Position::Pre && all_digits && identifier.len() > 1 && identifier.starts_with('0')
+        {
+            violations.push(StrictViolation {
+                span: span.clone(),
+                kind: ViolationKind::LeadingZero(pos),
+            });
+        }
+        start = span.end + 1;
+    }
+}
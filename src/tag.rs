@@ -0,0 +1,232 @@
+use crate::error::{ErrorKind, Template};
+use crate::parse::Error;
+use crate::Version;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// Format of the names given to a release: its git tag or the file name of
+/// its artifacts.
+///
+/// A scheme is a template in which `{name}` stands for the crate name and
+/// `{version}` for the version. [`TagScheme::format`] fills them in, and
+/// [`TagScheme::split`] goes the other way, from a tag to the name and
+/// version in it.
+///
+/// # Ambiguity
+///
+/// Both crate names and versions may contain `-`, so there can be more than
+/// one way to read a tag. `split` returns every reading in which the name is
+/// a valid crate name and the version parses by the grammar of
+/// [`Version::parse`]. For the predefined schemes there is never more than
+/// one: a crate name cannot contain `.`, so the name must end at the last
+/// separator before the first `.` of the version.
+///
+/// # Example
+///
+/// ```
+/// use semver::{TagScheme, Version};
+///
+/// let (name, version) = &TagScheme::CRATE_FILE.split("serde_json-1.0.108.crate")[0];
+/// assert_eq!(*name, "serde_json");
+/// assert_eq!(*version, Version::new(1, 0, 108));
+///
+/// let (name, version) = &TagScheme::NAME_VERSION.split("my-crate-0.1.0-alpha.1")[0];
+/// assert_eq!(*name, "my-crate");
+/// assert_eq!(version.to_string(), "0.1.0-alpha.1");
+///
+/// let scheme = TagScheme::new("releases/{name}@{version}").unwrap();
+/// assert_eq!(scheme.format("foo", &version), "releases/foo@0.1.0-alpha.1");
+/// ```
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TagScheme<'a> {
+    template: &'a str,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum Placeholder {
+    Name,
+    Version,
+}
+
+// The template split at its placeholders; one more literal than placeholders.
+struct Parts<'a> {
+    literals: Vec<&'a str>,
+    placeholders: Vec<Placeholder>,
+}
+
+impl TagScheme<'static> {
+    /// `{name}-{version}.crate`, the file names of packaged crates, such as
+    /// `serde_json-1.0.108.crate`.
+    pub const CRATE_FILE: Self = TagScheme {
+        template: "{name}-{version}.crate",
+    };
+
+    /// `{name}-{version}`, such as `my-crate-0.1.0-alpha.1`.
+    pub const NAME_VERSION: Self = TagScheme {
+        template: "{name}-{version}",
+    };
+
+    /// `{name}-v{version}`, tags of a crate in a workspace, such as
+    /// `foo-v2.3.4`.
+    pub const NAME_V_VERSION: Self = TagScheme {
+        template: "{name}-v{version}",
+    };
+
+    /// `v{version}`, tags of a repository with one crate, such as `v2.3.4`.
+    pub const V_VERSION: Self = TagScheme {
+        template: "v{version}",
+    };
+
+    /// `release/{version}`, such as `release/2.3.4`.
+    pub const RELEASE: Self = TagScheme {
+        template: "release/{version}",
+    };
+}
+
+impl<'a> TagScheme<'a> {
+    /// Create a scheme from a template containing `{version}` once, and
+    /// `{name}` at most once. Any other use of `{` or `}` is an error.
+    pub fn new(template: &'a str) -> Result<Self, Error> {
+        let parts = parts(template)?;
+        let count = |placeholder| {
+            parts
+                .placeholders
+                .iter()
+                .filter(|&&p| p == placeholder)
+                .count()
+        };
+        if count(Placeholder::Version) != 1 {
+            return Err(Error::new(ErrorKind::Template(Template::TagVersion)));
+        }
+        if count(Placeholder::Name) > 1 {
+            return Err(Error::new(ErrorKind::Template(Template::TagName)));
+        }
+        Ok(TagScheme { template })
+    }
+
+    /// The template that the scheme was created from.
+    pub fn template(&self) -> &'a str {
+        self.template
+    }
+
+    /// Every way of reading the text as a crate name and version according to
+    /// this scheme, in order of increasing length of the name.
+    ///
+    /// For a scheme without `{name}`, the name is the empty string.
+    pub fn split<'t>(&self, text: &'t str) -> Vec<(&'t str, Version)> {
+        let parts = self.parts();
+        let mut candidates = Vec::new();
+
+        let Some(rest) = text
+            .strip_prefix(parts.literals[0])
+            .and_then(|rest| rest.strip_suffix(parts.literals[parts.literals.len() - 1]))
+        else {
+            return candidates;
+        };
+
+        let [first, second] = match *parts.placeholders {
+            [_] => {
+                if let Ok(version) = Version::parse(rest) {
+                    candidates.push(("", version));
+                }
+                return candidates;
+            }
+            [first, second] => [first, second],
+            _ => unreachable!(),
+        };
+
+        let separator = parts.literals[1];
+        for i in (0..=rest.len()).filter(|&i| rest.is_char_boundary(i)) {
+            let Some(after) = rest[i..].strip_prefix(separator) else {
+                continue;
+            };
+            let (name, version) = match (first, second) {
+                (Placeholder::Name, _) => (&rest[..i], after),
+                (Placeholder::Version, _) => (after, &rest[..i]),
+            };
+            if !is_crate_name(name) {
+                continue;
+            }
+            if let Ok(version) = Version::parse(version) {
+                candidates.push((name, version));
+            }
+        }
+        if first == Placeholder::Version {
+            candidates.reverse();
+        }
+        candidates
+    }
+
+    /// Fill in the template with the given crate name and version.
+    pub fn format(&self, name: &str, version: &Version) -> String {
+        let parts = self.parts();
+        let mut string = String::new();
+        for (i, literal) in parts.literals.iter().enumerate() {
+            string.push_str(literal);
+            match parts.placeholders.get(i) {
+                Some(Placeholder::Name) => string.push_str(name),
+                Some(Placeholder::Version) => {
+                    let _ = write!(string, "{}", version);
+                }
+                None => {}
+            }
+        }
+        string
+    }
+
+    fn parts(&self) -> Parts<'a> {
+        match parts(self.template) {
+            Ok(parts) => parts,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+fn parts(template: &str) -> Result<Parts<'_>, Error> {
+    let (literals, placeholders) = split_template(template, &["{name}", "{version}"])?;
+    let placeholders = placeholders
+        .into_iter()
+        .map(|i| [Placeholder::Name, Placeholder::Version][i])
+        .collect();
+    Ok(Parts {
+        literals,
+        placeholders,
+    })
+}
+
+// Split a template at its placeholders, each given as an index into `names`,
+// with one more literal than placeholders. Any other use of `{` or `}` is an
+// error. Shared with the templates of DescribeFormat.
+pub(crate) fn split_template<'t>(
+    template: &'t str,
+    names: &[&str],
+) -> Result<(Vec<&'t str>, Vec<usize>), Error> {
+    let mut literals = Vec::new();
+    let mut placeholders = Vec::new();
+    let mut start = 0;
+    while let Some(i) = template[start..].find(['{', '}']) {
+        let offset = start + i;
+        let Some(index) = names
+            .iter()
+            .position(|name| template[offset..].starts_with(name))
+        else {
+            return Err(Error::new(ErrorKind::Template(Template::Placeholder(
+                offset,
+            ))));
+        };
+        literals.push(&template[start..offset]);
+        placeholders.push(index);
+        start = offset + names[index].len();
+    }
+    literals.push(&template[start..]);
+    Ok((literals, placeholders))
+}
+
+// The rules of crates.io: an ASCII letter followed by ASCII letters, digits,
+// `-` and `_`.
+fn is_crate_name(name: &str) -> bool {
+    let mut bytes = name.bytes();
+    bytes.next().map_or(false, |b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+}
@@ -0,0 +1,84 @@
+#![cfg(feature = "arbitrary")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use arbitrary::{Arbitrary, Unstructured};
+use semver::{BuildMetadata, Comparator, Prerelease, Version, VersionReq};
+
+// Deterministic pseudorandom bytes to drive Unstructured.
+fn data(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1;
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect()
+}
+
+#[test]
+fn test_roundtrip() {
+    for seed in 0..1000 {
+        let data = data(seed, 256);
+        let mut u = Unstructured::new(&data);
+        let version = Version::arbitrary(&mut u).unwrap();
+        let req = VersionReq::arbitrary(&mut u).unwrap();
+        let comparator = Comparator::arbitrary(&mut u).unwrap();
+        let pre = Prerelease::arbitrary(&mut u).unwrap();
+        let build = BuildMetadata::arbitrary(&mut u).unwrap();
+        assert_eq!(version.to_string().parse::<Version>().unwrap(), version);
+        assert_eq!(req.to_string().parse::<VersionReq>().unwrap(), req);
+        assert_eq!(
+            comparator.to_string().parse::<Comparator>().unwrap(),
+            comparator,
+        );
+        assert_eq!(Prerelease::new(pre.as_str()).unwrap(), pre);
+        assert_eq!(BuildMetadata::new(build.as_str()).unwrap(), build);
+    }
+}
+
+#[test]
+fn test_exhausted() {
+    // Running out of input produces zeros rather than an error.
+    let mut u = Unstructured::new(&[]);
+    assert_eq!(Version::arbitrary(&mut u).unwrap(), version("0.0.0"));
+}
+
+#[test]
+fn test_corpus() {
+    for r in REQ_CORPUS {
+        let r = req(r);
+        for seed in 0..100 {
+            let data = data(seed, 64);
+            let mut u = Unstructured::new(&data);
+            if r.to_string() != ">18446744073709551615" {
+                let v = semver::arbitrary::matching(&mut u, &r).unwrap();
+                assert!(r.matches(&v), "{} {}", r, v);
+            }
+            let v = semver::arbitrary::not_matching(&mut u, &r).unwrap();
+            assert!(!r.matches(&v), "{} {}", r, v);
+        }
+    }
+}
+
+#[test]
+fn test_unsatisfiable() {
+    let bytes = data(0, 64);
+    let mut u = Unstructured::new(&bytes);
+    let err = semver::arbitrary::matching(&mut u, &req(">1, <1")).unwrap_err();
+    assert!(matches!(err, arbitrary::Error::IncorrectFormat));
+
+    // Requirements from the crate's own generator never make it panic.
+    for seed in 0..1000 {
+        let bytes = data(seed, 64);
+        let mut u = Unstructured::new(&bytes);
+        let r = VersionReq::arbitrary(&mut u).unwrap();
+        if let Ok(v) = semver::arbitrary::matching(&mut u, &r) {
+            assert!(r.matches(&v), "{} {}", r, v);
+        }
+    }
+}
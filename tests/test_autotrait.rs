@@ -6,9 +6,14 @@ fn assert_send_sync<T: Send + Sync>() {}
 fn test() {
     assert_send_sync::<semver::BuildMetadata>();
     assert_send_sync::<semver::Comparator>();
+    assert_send_sync::<semver::ComparatorRef>();
     assert_send_sync::<semver::Error>();
     assert_send_sync::<semver::Prerelease>();
     assert_send_sync::<semver::Version>();
+    assert_send_sync::<semver::VersionRef>();
     assert_send_sync::<semver::VersionReq>();
     assert_send_sync::<semver::Op>();
+    assert_send_sync::<semver::PackedVersion>();
+    assert_send_sync::<semver::PackedVersion128>();
+    assert_send_sync::<semver::StrictError>();
 }
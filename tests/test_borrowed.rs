@@ -0,0 +1,109 @@
+#![allow(clippy::uninlined_format_args, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Comparator, ComparatorRef, Version, VersionRef};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_parse() {
+    let text = "1.0.0-alpha.1234567890+build.2026-10-18";
+    let version = VersionRef::parse(text).unwrap();
+    assert_eq!(version.major(), 1);
+    assert_eq!(version.minor(), 0);
+    assert_eq!(version.patch(), 0);
+    assert_eq!(version.pre(), "alpha.1234567890");
+    assert_eq!(version.build(), "build.2026-10-18");
+    assert!(std::ptr::eq(version.pre(), &text[6..22]));
+    assert_eq!(version.to_version(), Version::parse(text).unwrap());
+    assert_to_string(version, text);
+    assert_eq!(
+        format!("{:?}", version),
+        "VersionRef { major: 1, minor: 0, patch: 0, pre: \"alpha.1234567890\", build: \"build.2026-10-18\" }",
+    );
+
+    for text in VERSION_CORPUS
+        .iter()
+        .chain(REQ_CORPUS)
+        .chain(&["", "1.2.3-", "1.2.3+x y"])
+    {
+        match (Version::parse(text), VersionRef::parse(text)) {
+            (Ok(owned), Ok(borrowed)) => {
+                assert_eq!(owned, borrowed.to_version());
+                assert_eq!(VersionRef::from(&owned), borrowed);
+                assert_eq!(hash(&owned), hash(&borrowed));
+                assert_eq!(owned.to_string(), borrowed.to_string());
+            }
+            (Err(owned), Err(borrowed)) => assert_eq!(owned.to_string(), borrowed.to_string()),
+            (owned, borrowed) => panic!("{:?}: {:?} vs {:?}", text, owned, borrowed),
+        }
+    }
+}
+
+#[test]
+fn test_comparator_parse() {
+    let text = ">= 1.2.3-beta.longer.than.eight+ignored";
+    let comparator = ComparatorRef::parse(text).unwrap();
+    assert_eq!(comparator.pre(), "beta.longer.than.eight");
+    assert_eq!(comparator.to_comparator(), Comparator::parse(text).unwrap());
+    assert_to_string(comparator, ">=1.2.3-beta.longer.than.eight");
+
+    for text in REQ_CORPUS.iter().chain(&["1.*.*", "~1", "<=1.2.3 x"]) {
+        match (Comparator::parse(text), ComparatorRef::parse(text)) {
+            (Ok(owned), Ok(borrowed)) => {
+                assert_eq!(owned, borrowed.to_comparator());
+                assert_eq!(ComparatorRef::from(&owned), borrowed);
+                assert_eq!(owned.to_string(), borrowed.to_string());
+            }
+            (Err(owned), Err(borrowed)) => assert_eq!(owned.to_string(), borrowed.to_string()),
+            (owned, borrowed) => panic!("{:?}: {:?} vs {:?}", text, owned, borrowed),
+        }
+    }
+}
+
+#[test]
+fn test_order() {
+    let owned: Vec<Version> = VERSION_CORPUS.iter().map(|v| version(v)).collect();
+    let borrowed: Vec<VersionRef> = VERSION_CORPUS
+        .iter()
+        .map(|v| VersionRef::parse(v).unwrap())
+        .collect();
+
+    for (a, a_ref) in owned.iter().zip(&borrowed) {
+        for (b, b_ref) in owned.iter().zip(&borrowed) {
+            assert_eq!(a.cmp(b), a_ref.cmp(b_ref), "{} vs {}", a, b);
+            assert_eq!(a.cmp_precedence(b), a_ref.cmp_precedence(b_ref));
+            assert_eq!(a == b, a_ref == b_ref);
+        }
+    }
+}
+
+#[test]
+fn test_matches() {
+    for r in REQ_CORPUS {
+        let req = req(r);
+        for v in VERSION_CORPUS {
+            let owned = version(v);
+            let borrowed = VersionRef::parse(v).unwrap();
+            assert_eq!(
+                req.matches(&owned),
+                req.matches_ref(&borrowed),
+                "{} {}",
+                r,
+                v
+            );
+            for cmp in &req.comparators {
+                let cmp_ref = ComparatorRef::from(cmp);
+                assert_eq!(cmp.matches(&owned), cmp_ref.matches(&borrowed));
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+#![allow(
+    clippy::toplevel_ref_arg,
+    clippy::uninlined_format_args,
+    clippy::wildcard_imports
+)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Comparator, Op, Prerelease, Version, VersionReq};
+
+fn comparators(numbers: &[u64], pres: &[&str]) -> Vec<Comparator> {
+    let ops = [
+        Op::Exact,
+        Op::Greater,
+        Op::GreaterEq,
+        Op::Less,
+        Op::LessEq,
+        Op::Tilde,
+        Op::Caret,
+        Op::Wildcard,
+    ];
+    let mut comparators = Vec::new();
+    for op in ops {
+        for &major in numbers {
+            let mut partial = vec![(None, None, "")];
+            for &minor in numbers {
+                partial.push((Some(minor), None, ""));
+                if op == Op::Wildcard {
+                    continue;
+                }
+                for &patch in numbers {
+                    for pre in pres {
+                        partial.push((Some(minor), Some(patch), pre));
+                    }
+                }
+            }
+            for (minor, patch, pre) in partial {
+                comparators.push(Comparator {
+                    op,
+                    major,
+                    minor,
+                    patch,
+                    pre: prerelease(pre),
+                });
+            }
+        }
+    }
+    comparators
+}
+
+fn versions(numbers: &[u64], pres: &[&str]) -> Vec<Version> {
+    let mut versions = Vec::new();
+    for &major in numbers {
+        for &minor in numbers {
+            for &patch in numbers {
+                for pre in pres {
+                    versions.push(Version {
+                        pre: Prerelease::new(pre).unwrap(),
+                        ..Version::new(major, minor, patch)
+                    });
+                }
+            }
+        }
+    }
+    versions
+}
+
+#[track_caller]
+fn assert_agrees(req: &VersionReq, versions: &[Version]) {
+    let compiled = req.compile();
+    for version in versions {
+        assert_eq!(
+            compiled.matches(version),
+            req.matches(version),
+            "{} {}",
+            req,
+            version,
+        );
+    }
+}
+
+#[test]
+fn test_single_comparator() {
+    let versions = versions(&[0, 1, 2, 3], &["", "0", "alpha", "beta.2"]);
+    for cmp in comparators(&[0, 1, 2], &["", "alpha", "beta.2"]) {
+        assert_agrees(&VersionReq::from_iter([cmp]), &versions);
+    }
+}
+
+#[test]
+fn test_two_comparators() {
+    let versions = versions(&[0, 1, 2], &["", "alpha", "beta"]);
+    let comparators = comparators(&[0, 1], &["", "alpha"]);
+    for a in &comparators {
+        for b in &comparators {
+            assert_agrees(&VersionReq::from_iter([a.clone(), b.clone()]), &versions);
+        }
+    }
+}
+
+#[test]
+fn test_corpus() {
+    let versions: Vec<Version> = VERSION_CORPUS.iter().map(|v| version(v)).collect();
+    for r in REQ_CORPUS {
+        assert_agrees(&req(r), &versions);
+    }
+}
+
+#[test]
+fn test_matches_many() {
+    let compiled = req("^1.2.3").compile();
+    let versions = [
+        version("1.2.2"),
+        version("1.2.3"),
+        version("1.9.0-rc.1"),
+        version("1.9.0"),
+        version("2.0.0"),
+    ];
+    assert_eq!(
+        compiled.matches_many(&versions),
+        [false, true, false, true, false],
+    );
+}
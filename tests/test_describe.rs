@@ -0,0 +1,83 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+
+#[track_caller]
+fn assert_describe(text: &str, expected: &str) {
+    assert_eq!(req(text).describe(), expected);
+}
+
+#[test]
+fn test_describe() {
+    assert_describe("*", "any version that is not a pre-release");
+
+    assert_describe("=1.2.3", "exactly 1.2.3");
+    assert_describe("=1.2", "any 1.2.x version");
+    assert_describe("=1", "any 1.x version");
+
+    assert_describe(">1.2.3", "any version above 1.2.3");
+    assert_describe(">1.2", "any version at or above 1.3.0");
+    assert_describe(">1", "any version at or above 2.0.0");
+    assert_describe(">1.18446744073709551615", "any version at or above 2.0.0");
+    assert_describe(">18446744073709551615", "no version");
+
+    assert_describe(">=1.2.3", "any version at or above 1.2.3");
+    assert_describe(">=1.2", "any version at or above 1.2.0");
+    assert_describe(">=1", "any version at or above 1.0.0");
+
+    assert_describe("<1.2.3", "any version below 1.2.3");
+    assert_describe("<1.2", "any version below 1.2.0");
+    assert_describe("<1", "any version below 1.0.0");
+
+    assert_describe("<=1.2.3-rc.1", "any version at or below 1.2.3-rc.1");
+    assert_describe("<=1.2", "any version below 1.3.0");
+    assert_describe("<=1", "any version below 2.0.0");
+
+    assert_describe(
+        "~1.2.3",
+        "any 1.2.x version at or above 1.2.3 (tilde only allows patch updates)",
+    );
+    assert_describe("~1.2", "any 1.2.x version");
+    assert_describe("~1", "any 1.x version");
+
+    assert_describe(
+        "^1.2.3",
+        "any 1.x version at or above 1.2.3 (caret allows minor and patch updates)",
+    );
+    assert_describe(
+        "^0.2.3",
+        "any 0.2.x version at or above 0.2.3 (0.x caret only allows patch updates)",
+    );
+    assert_describe(
+        "^0.0.3",
+        "exactly 0.0.3 (0.0.x caret does not allow any updates)",
+    );
+    assert_describe(
+        "^0.0.3-beta",
+        "0.0.3 or a pre-release of it at or above 0.0.3-beta (0.0.x caret does not allow any updates)",
+    );
+    assert_describe(
+        "^1.2",
+        "any 1.x version at or above 1.2.0 (caret allows minor and patch updates)",
+    );
+    assert_describe("^0.2", "any 0.2.x version");
+    assert_describe("^0.0", "any 0.0.x version");
+    assert_describe("^1", "any 1.x version");
+
+    assert_describe("1.2.*", "any 1.2.x version");
+    assert_describe("1.*", "any 1.x version");
+    assert_describe("0.0.*", "any 0.0.x version");
+
+    assert_describe(
+        ">=1.2.3, <1.8",
+        "all of: any version at or above 1.2.3; any version below 1.8.0",
+    );
+}
+
+#[test]
+fn test_describe_comparator() {
+    let cmp = comparator("~1");
+    assert_eq!(cmp.describe(), "any 1.x version");
+}
@@ -0,0 +1,168 @@
+#![allow(clippy::uninlined_format_args)]
+
+use semver::cmp_embedded;
+use std::cmp::Ordering;
+
+fn sorted(strings: &[&'static str]) -> Vec<&'static str> {
+    let mut sorted = strings.to_vec();
+    sorted.reverse();
+    sorted.sort_by(|a, b| cmp_embedded(a, b));
+    sorted
+}
+
+#[test]
+fn test_file_names() {
+    let expected = [
+        "tool-0.9.0-linux.tar.gz",
+        "tool-1.9.2-linux.tar.gz",
+        "tool-1.9.2-macos.tar.gz",
+        "tool-1.10.0-alpha.2.tar.gz",
+        "tool-1.10.0-alpha.10.tar.gz",
+        "tool-1.10.0.tar.gz",
+        "tool-1.10.0+build.5.tar.gz",
+        "tool-12.0.0.tar.gz",
+        "toolkit-0.1.0.tar.gz",
+    ];
+    assert_eq!(sorted(&expected), expected);
+}
+
+#[test]
+fn test_shared_suffix() {
+    // Text that both strings have after the version is not pre-release.
+    let expected = [
+        "tool-1.9.2-linux.tar.gz",
+        "tool-1.10.0-rc.1-linux.tar.gz",
+        "tool-1.10.0-rc.2-linux.tar.gz",
+        "tool-1.10.0-rc.10-linux.tar.gz",
+        "tool-1.10.0-linux.tar.gz",
+        "tool-1.10.0+build.5-linux.tar.gz",
+    ];
+    assert_eq!(sorted(&expected), expected);
+
+    assert_eq!(
+        cmp_embedded("tool-1.10.0-rc.1-linux.tar.gz", "tool-1.10.0-linux.tar.gz"),
+        Ordering::Less,
+    );
+    assert_eq!(
+        cmp_embedded("tool-1.10.0-linux.tar.gz", "tool-1.10.0-rc.1-linux.tar.gz"),
+        Ordering::Greater,
+    );
+    assert_eq!(
+        cmp_embedded("v1.0.0-beta.1-x86_64", "v1.0.0-x86_64"),
+        Ordering::Less,
+    );
+
+    // An identifier without digits is text, compared by bytes after the
+    // version.
+    assert_eq!(
+        cmp_embedded("tool-1.9.2-linux.tar.gz", "tool-1.9.2.tar.gz"),
+        Ordering::Less,
+    );
+}
+
+#[test]
+fn test_tags() {
+    let expected = [
+        "release/v1.0.0",
+        "release/v1.2.0",
+        "release/v1.11.0",
+        "v0.1.0-1",
+        "v0.1.0",
+        "v0.10.0",
+        "v2.0.0-rc.1",
+        "v2.0.0",
+    ];
+    assert_eq!(sorted(&expected), expected);
+}
+
+#[test]
+fn test_several_versions() {
+    assert_eq!(
+        cmp_embedded("a-1.0.0-to-1.10.0", "a-1.0.0-to-1.9.0"),
+        Ordering::Greater
+    );
+    assert_eq!(
+        cmp_embedded("2.0.0..3.0.0", "10.0.0..1.0.0"),
+        Ordering::Less
+    );
+    assert_eq!(cmp_embedded("1.0.0/2.0.0", "1.0.0/10.0.0"), Ordering::Less);
+}
+
+#[test]
+fn test_not_versions() {
+    // Without an embedded version, byte order.
+    assert_eq!(cmp_embedded("file10", "file9"), Ordering::Less);
+    assert_eq!(cmp_embedded("abc", "abd"), Ordering::Less);
+    assert_eq!(cmp_embedded("", "a"), Ordering::Less);
+    assert_eq!(cmp_embedded("same", "same"), Ordering::Equal);
+
+    // Partial versions, leading zeros, and numbers beyond u64 are not
+    // versions.
+    assert_eq!(cmp_embedded("1.10", "1.9"), Ordering::Less);
+    assert_eq!(cmp_embedded("x-01.10.0", "x-01.9.0"), Ordering::Less);
+    assert_eq!(
+        cmp_embedded("18446744073709551616.10.0", "18446744073709551616.9.0"),
+        Ordering::Less,
+    );
+
+    // Digits that continue a longer number do not start a version.
+    assert_eq!(cmp_embedded("018.10.0", "018.9.0"), Ordering::Less);
+    assert_eq!(
+        cmp_embedded("build18.10.0", "build18.9.0"),
+        Ordering::Greater
+    );
+}
+
+#[test]
+fn test_version_next_to_text() {
+    // A version only on one side falls back to byte order from there.
+    assert_eq!(cmp_embedded("v1.0.0", "vX"), Ordering::Less);
+    assert_eq!(cmp_embedded("v1.0.0", "v1.0"), Ordering::Greater);
+
+    // Trailing dots and invalid pre-release are left out of the version.
+    assert_eq!(cmp_embedded("1.0.0-rc.1.", "1.0.0-rc.1"), Ordering::Greater);
+    assert_eq!(cmp_embedded("1.0.0.", "1.0.0-rc.1"), Ordering::Greater);
+    assert_eq!(cmp_embedded("1.10.0-01", "1.9.0-01"), Ordering::Greater);
+}
+
+#[test]
+fn test_build_metadata() {
+    assert_eq!(cmp_embedded("1.0.0+7", "1.0.0+07"), Ordering::Less);
+    assert_eq!(cmp_embedded("1.0.0+07", "1.0.0+7"), Ordering::Greater);
+    assert_eq!(cmp_embedded("x1.0.0y", "x1.0.0y"), Ordering::Equal);
+}
+
+// Too slow for miri, and there is no unsafe code involved.
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_total_order() {
+    let bases = ["tool-1.0.0", "tool-1.10.0", "tool-1.9.2", "tool-1.10"];
+    let suffixes = ["-alpha", "+build.5", "-linux", "-rc.1", ".tar.gz"];
+
+    let mut strings = Vec::new();
+    for base in bases {
+        let mut names = vec![base.to_owned()];
+        for _ in 0..3 {
+            let longer: Vec<String> = names
+                .iter()
+                .flat_map(|name| suffixes.iter().map(move |suffix| name.clone() + suffix))
+                .collect();
+            strings.extend(names);
+            names = longer;
+        }
+        strings.extend(names);
+    }
+
+    strings.sort_by(|a, b| cmp_embedded(a, b));
+
+    // Given antisymmetry, a consistent sorted order implies transitivity: if
+    // a < b < c then a, b, c appear in that order, so a > c is caught below.
+    for (i, a) in strings.iter().enumerate() {
+        for b in &strings[i..] {
+            let ordering = cmp_embedded(a, b);
+            assert_ne!(ordering, Ordering::Greater, "{} {}", a, b);
+            assert_eq!(ordering == Ordering::Equal, a == b, "{} {}", a, b);
+            assert_eq!(cmp_embedded(b, a), ordering.reverse(), "{} {}", a, b);
+        }
+    }
+}
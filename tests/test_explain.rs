@@ -0,0 +1,104 @@
+#![allow(
+    clippy::needless_pass_by_value,
+    clippy::toplevel_ref_arg,
+    clippy::uninlined_format_args,
+    clippy::wildcard_imports
+)]
+
+mod util;
+
+use crate::util::*;
+use semver::{PrereleaseRule, Version, Violation};
+use std::ops::Bound;
+
+#[test]
+fn test_matches_agrees() {
+    for r in REQ_CORPUS {
+        let ref r = req(r);
+        for v in VERSION_CORPUS {
+            let ref v = version(v);
+            let explanation = r.explain(v);
+            assert_eq!(explanation.matches(), r.matches(v), "{}", explanation);
+        }
+    }
+}
+
+#[test]
+fn test_bounds() {
+    let ref r = req("^1.2.3");
+    let ref v = version("2.0.0-beta");
+    let explanation = r.explain(v);
+    assert_eq!(
+        explanation.comparators[0].violation,
+        Some(Violation::Above(Bound::Excluded(version("2.0.0-0")))),
+    );
+
+    let ref v = version("1.2.2");
+    let explanation = r.explain(v);
+    assert_eq!(
+        explanation.comparators[0].violation,
+        Some(Violation::Below(Bound::Included(version("1.2.3")))),
+    );
+
+    let ref r = req(">1.2");
+    let ref v = version("1.2.9");
+    let explanation = r.explain(v);
+    assert_eq!(
+        explanation.comparators[0].violation,
+        Some(Violation::Below(Bound::Included(version("1.3.0-0")))),
+    );
+}
+
+#[test]
+fn test_prerelease() {
+    let ref r = req(">=1.2, <1.2.7-rc.1");
+    let ref v = version("1.2.5-rc.1");
+    let explanation = r.explain(v);
+    assert!(!explanation.matches());
+    assert!(matches!(explanation.prerelease, PrereleaseRule::Excluded));
+    assert!(!explanation.is_excluded_only_by_prerelease_rule());
+    assert_eq!(
+        explanation.comparators[0].violation,
+        Some(Violation::Prerelease),
+    );
+    assert_to_string(
+        explanation,
+        "1.2.5-rc.1 does not match >=1.2, <1.2.7-rc.1\n  \
+         >=1.2: violated, >=1.2 does not match pre-releases of 1.2.*\n  \
+         <1.2.7-rc.1: satisfied\n  \
+         note: pre-release 1.2.5-rc.1 is only matched by a requirement that has a comparator with version 1.2.5 and a pre-release of its own",
+    );
+
+    let ref r = req(">=1.2.3");
+    let ref v = version("1.4.0-rc.1");
+    let explanation = r.explain(v);
+    assert!(explanation.is_excluded_only_by_prerelease_rule());
+    assert_to_string(
+        explanation,
+        "1.4.0-rc.1 does not match >=1.2.3\n  \
+         >=1.2.3: satisfied\n  \
+         note: pre-release 1.4.0-rc.1 is only matched by a requirement that has a comparator with version 1.4.0 and a pre-release of its own; this is the only reason it does not match",
+    );
+
+    let ref r = req(">=1.2.3-alpha, <2");
+    let ref v = version("1.2.3-beta.2");
+    let explanation = r.explain(v);
+    assert!(explanation.matches());
+    assert_to_string(
+        explanation,
+        "1.2.3-beta.2 matches >=1.2.3-alpha, <2\n  \
+         >=1.2.3-alpha: satisfied\n  \
+         <2: satisfied\n  \
+         pre-release allowed by >=1.2.3-alpha",
+    );
+}
+
+#[test]
+fn test_star() {
+    let ref r = req("*");
+    let v = Version::new(1, 0, 0);
+    let explanation = r.explain(&v);
+    assert!(explanation.matches());
+    assert!(explanation.comparators.is_empty());
+    assert_to_string(explanation, "1.0.0 matches *");
+}
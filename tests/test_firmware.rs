@@ -0,0 +1,117 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::firmware::{Endian, ImageVersion, Layout};
+use std::convert::TryFrom;
+
+const BIG: Layout = Layout::new(2, 2, 2, 0, Endian::Big);
+const WIDE: Layout = Layout::new(8, 8, 8, 8, Endian::Little);
+
+#[test]
+fn test_mcuboot() {
+    const HEADER: [u8; 8] = match Layout::MCUBOOT.encode(ImageVersion::new(1, 2, 0x0304, 5)) {
+        Ok(bytes) => bytes,
+        Err(_) => panic!(),
+    };
+    const DECODED: ImageVersion = match Layout::MCUBOOT.decode(&HEADER) {
+        Ok(version) => version,
+        Err(_) => panic!(),
+    };
+    assert_eq!(Layout::MCUBOOT.size(), 8);
+    assert_eq!(HEADER, [1, 2, 4, 3, 5, 0, 0, 0]);
+    assert_eq!(DECODED, ImageVersion::new(1, 2, 0x0304, 5));
+
+    let decoded = Layout::MCUBOOT.decode_version(&HEADER).unwrap();
+    assert_eq!(decoded, version("1.2.772+5"));
+    let bytes: [u8; 8] = Layout::MCUBOOT.encode_version(&decoded).unwrap();
+    assert_eq!(bytes, HEADER);
+
+    let bytes: [u8; 8] = Layout::MCUBOOT.encode_version(&version("1.0.0")).unwrap();
+    assert_eq!(bytes, [1, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        Layout::MCUBOOT.decode_version(&bytes).unwrap(),
+        version("1.0.0"),
+    );
+}
+
+#[test]
+fn test_endian() {
+    let bytes: [u8; 6] = BIG.encode(ImageVersion::new(1, 0x0203, 0xffff, 0)).unwrap();
+    assert_eq!(bytes, [0, 1, 2, 3, 0xff, 0xff]);
+    assert_eq!(
+        BIG.decode(&bytes).unwrap(),
+        ImageVersion::new(1, 0x0203, 0xffff, 0),
+    );
+    assert_eq!(BIG.endian(), Endian::Big);
+
+    let max = ImageVersion::new(u64::MAX, 0, 1, u64::MAX - 1);
+    let bytes: [u8; 32] = WIDE.encode(max).unwrap();
+    assert_eq!(bytes[..8], [0xff; 8]);
+    assert_eq!(bytes[16], 1);
+    assert_eq!(bytes[24], 0xfe);
+    assert_eq!(WIDE.decode(&bytes).unwrap(), max);
+    assert_eq!(
+        WIDE.decode_version(&bytes).unwrap(),
+        version("18446744073709551615.0.1+18446744073709551614"),
+    );
+}
+
+#[test]
+fn test_errors() {
+    let error = Layout::MCUBOOT
+        .encode::<8>(ImageVersion::new(256, 0, 0, 0))
+        .unwrap_err();
+    assert_to_string(
+        error,
+        "value of major version number does not fit in 1 byte",
+    );
+
+    let error = Layout::MCUBOOT
+        .encode::<8>(ImageVersion::new(0, 0, 0x10000, 0))
+        .unwrap_err();
+    assert_to_string(
+        error,
+        "value of patch version number does not fit in 2 bytes",
+    );
+
+    let error = BIG.encode::<6>(ImageVersion::new(0, 0, 0, 1)).unwrap_err();
+    assert_to_string(error, "value of build metadata does not fit in 0 bytes");
+
+    let error = Layout::MCUBOOT
+        .encode::<4>(ImageVersion::default())
+        .unwrap_err();
+    assert_to_string(error, "packed version layout has 8 bytes, found 4");
+
+    let error = Layout::MCUBOOT.decode(&[0; 9]).unwrap_err();
+    assert_to_string(error, "packed version layout has 8 bytes, found 9");
+
+    let error = Layout::MCUBOOT
+        .encode_version::<8>(&version("1.0.0-rc.1"))
+        .unwrap_err();
+    assert_to_string(error, "pre-release cannot be stored in a packed version");
+
+    for build in ["1.0.0+abc", "1.0.0+1.2", "1.0.0+007"] {
+        let error = ImageVersion::try_from(&version(build)).unwrap_err();
+        assert_to_string(
+            error,
+            "build metadata must be a decimal number to be stored in a packed version",
+        );
+    }
+
+    let error = ImageVersion::try_from(&version("1.0.0+18446744073709551616")).unwrap_err();
+    assert_to_string(error, "value of build metadata exceeds u64::MAX");
+}
+
+#[test]
+fn test_version_conversion() {
+    for text in ["0.0.0", "1.2.3+0", "1.2.3+9", "4.5.6+1234567890"] {
+        let image = ImageVersion::try_from(&version(text)).unwrap();
+        let expected = if text.ends_with("+0") { "1.2.3" } else { text };
+        assert_to_string(semver::Version::from(image), expected);
+    }
+
+    assert!(ImageVersion::new(1, 2, 3, 4) < ImageVersion::new(1, 2, 3, 5));
+    assert!(ImageVersion::new(1, 2, 3, 9) < ImageVersion::new(1, 2, 4, 0));
+}
@@ -0,0 +1,116 @@
+#![allow(clippy::uninlined_format_args, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Comparator, FixedVersionReq, Version, VersionRef};
+
+#[test]
+fn test_basic() {
+    let req = FixedVersionReq::<4>::parse(" >= 1.2.3 , <1.8").unwrap();
+    assert_eq!(req.comparators().len(), 2);
+    assert_eq!(req.to_string(), ">=1.2.3, <1.8");
+    assert!(req.matches(&Version::parse("1.2.3").unwrap()));
+    assert!(!req.matches(&Version::parse("1.8.0").unwrap()));
+    assert!(req.matches_ref(&VersionRef::parse("1.7.99").unwrap()));
+    assert!(!req.matches_ref(&VersionRef::parse("1.7.0-alpha").unwrap()));
+
+    let star = FixedVersionReq::<4>::parse("*").unwrap();
+    assert!(star.comparators().is_empty());
+    assert_eq!(star, FixedVersionReq::new());
+    assert_eq!(star.to_string(), "*");
+
+    let error = FixedVersionReq::<4>::parse("1.0, *").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "wildcard req (*) must be the only comparator in the version req",
+    );
+}
+
+#[test]
+fn test_capacity() {
+    let error = FixedVersionReq::<2>::parse(">1, <3, !=2").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unexpected character '!' while parsing major version number"
+    );
+
+    let error = FixedVersionReq::<2>::parse(">1, <3, =2").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "version req exceeds FixedVersionReq capacity of 2 comparators",
+    );
+
+    let mut req = FixedVersionReq::<0>::new();
+    let comparator = Comparator::parse("^1").unwrap();
+    assert_eq!(req.push(comparator.clone()), Err(comparator));
+    assert_eq!(FixedVersionReq::<0>::parse("*").unwrap(), req);
+
+    let mut req = FixedVersionReq::<1>::new();
+    req.push(Comparator::parse("~1.2").unwrap()).unwrap();
+    assert_eq!(req.to_string(), "~1.2");
+}
+
+#[test]
+fn test_inline() {
+    let error = Version::parse_inline("1.0.0-alpha.123+build.05").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "pre-release identifier longer than 8 bytes cannot be stored without allocating",
+    );
+    let error = Version::parse_inline("1.0.0-alpha+build.005").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "build metadata longer than 8 bytes cannot be stored without allocating",
+    );
+
+    let version = Version::parse_inline("1.0.0-alpha.1+build.05").unwrap();
+    assert_eq!(version.pre.as_str(), "alpha.1");
+    assert_eq!(version.build.as_str(), "build.05");
+
+    // Borrowing works regardless of length.
+    let version = VersionRef::parse("1.0.0-alpha.123+build.05").unwrap();
+    assert_eq!(version.pre(), "alpha.123");
+
+    let error = FixedVersionReq::<2>::parse(">=1.0.0-alpha.beta").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "pre-release identifier longer than 8 bytes cannot be stored without allocating",
+    );
+}
+
+#[test]
+fn test_agrees_with_version_req() {
+    for r in REQ_CORPUS {
+        let owned = req(r);
+        let fixed = FixedVersionReq::<32>::parse(r).unwrap();
+        assert_eq!(owned.comparators, fixed.comparators());
+        assert_eq!(owned.to_string(), fixed.to_string());
+        for v in VERSION_CORPUS {
+            let version = version(v);
+            assert_eq!(
+                owned.matches(&version),
+                fixed.matches(&version),
+                "{} {}",
+                r,
+                v
+            );
+        }
+    }
+
+    for r in [
+        "",
+        ">",
+        "1.2.3 - 2",
+        "1,",
+        "*, 1",
+        "x 1",
+        "1.*.1",
+        ">=1.0.0-",
+        "<1.x.3",
+    ] {
+        let owned = semver::VersionReq::parse(r).unwrap_err();
+        let fixed = FixedVersionReq::<32>::parse(r).unwrap_err();
+        assert_eq!(owned.to_string(), fixed.to_string(), "{:?}", r);
+    }
+}
@@ -0,0 +1,142 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{DescribeFormat, TagScheme, Version};
+
+fn describe(text: &str) -> Version {
+    Version::from_git_describe(text).unwrap()
+}
+
+#[test]
+fn test_from_git_describe() {
+    assert_eq!(describe("v1.2.3"), version("1.2.3"));
+    assert_eq!(describe("1.2.3"), version("1.2.3"));
+    assert_eq!(describe("v1.2.3-0-gabc1234"), version("1.2.3"));
+    assert_eq!(
+        describe("v1.2.3-14-gabc1234"),
+        version("1.2.4-dev.14+gabc1234"),
+    );
+    assert_eq!(
+        describe("v1.2.3-14-gabc1234-dirty"),
+        version("1.2.4-dev.14+gabc1234.dirty"),
+    );
+    assert_eq!(describe("v1.2.3-dirty"), version("1.2.4-dev.0+dirty"));
+    assert_eq!(
+        describe("v1.2.3-0-gabc1234-dirty"),
+        version("1.2.4-dev.0+gabc1234.dirty"),
+    );
+
+    // Pre-release tags.
+    assert_eq!(describe("v2.0.0-rc.1"), version("2.0.0-rc.1"));
+    assert_eq!(
+        describe("v2.0.0-rc.1-3-g1f2e"),
+        version("2.0.0-rc.1.dev.3+g1f2e"),
+    );
+    assert_eq!(
+        describe("v2.0.0-good-3-g1f2e"),
+        version("2.0.0-good.dev.3+g1f2e"),
+    );
+}
+
+#[test]
+fn test_ordering() {
+    let versions = [
+        describe("v1.2.3"),
+        describe("v1.2.3-dirty"),
+        describe("v1.2.3-2-gffff"),
+        describe("v1.2.3-9-g0000"),
+        describe("v1.2.3-10-g1234"),
+        describe("v1.2.3-10-g1234-dirty"),
+        describe("v1.2.3-100-g1234"),
+        describe("v1.2.4-rc.1"),
+        describe("v1.2.4-rc.1-5-g1234"),
+        describe("v1.2.4-rc.2"),
+        describe("v1.2.4"),
+    ];
+    for pair in versions.windows(2) {
+        assert!(pair[0] < pair[1], "{} < {}", pair[0], pair[1]);
+    }
+}
+
+#[test]
+fn test_format() {
+    const FORMAT: DescribeFormat = DescribeFormat::new()
+        .tag_scheme(TagScheme::RELEASE)
+        .pre("alpha.0.{count}")
+        .build("{hash}")
+        .dirty("wip");
+    let describe = |text| Version::from_git_describe_with(text, &FORMAT);
+
+    assert_eq!(describe("release/1.2.3").unwrap(), version("1.2.3"));
+    assert_eq!(
+        describe("release/1.2.3-7-gdeadbeef-dirty").unwrap(),
+        version("1.2.4-alpha.0.7+deadbeef.wip"),
+    );
+    assert_to_string(
+        describe("v1.2.3-7-gdeadbeef").unwrap_err(),
+        "tag in git describe output does not match the tag scheme",
+    );
+
+    let format = DescribeFormat::new().tag_scheme(TagScheme::NAME_V_VERSION);
+    assert_eq!(
+        Version::from_git_describe_with("my-crate-v0.1.0-3-g1234", &format).unwrap(),
+        version("0.1.1-dev.3+g1234"),
+    );
+
+    assert_eq!(
+        Version::from_git_describe_with("v1.0.0", &DescribeFormat::default()).unwrap(),
+        version("1.0.0"),
+    );
+}
+
+#[test]
+fn test_errors() {
+    assert_to_string(
+        Version::from_git_describe("release-1.2.3-4-g1234").unwrap_err(),
+        "unexpected character 'r' while parsing major version number",
+    );
+    assert_to_string(
+        Version::from_git_describe("v1.2.18446744073709551615-1-g1234").unwrap_err(),
+        "value of patch version number exceeds u64::MAX",
+    );
+
+    let format = DescribeFormat::new().pre("dev.{commits}");
+    assert_to_string(
+        Version::from_git_describe_with("v1.2.3-1-g1234", &format).unwrap_err(),
+        "unknown placeholder or unmatched brace at byte 4 of template",
+    );
+
+    let format = DescribeFormat::new().pre("");
+    assert_to_string(
+        Version::from_git_describe_with("v1.2.3-1-g1234", &format).unwrap_err(),
+        "pre-release template of the describe format expands to nothing",
+    );
+
+    let format = DescribeFormat::new().pre("{hash}");
+    assert_eq!(
+        Version::from_git_describe_with("v1.2.3-1-g1234", &format).unwrap(),
+        version("1.2.4-1234+g1234"),
+    );
+    assert_to_string(
+        Version::from_git_describe_with("v1.2.3-dirty", &format).unwrap_err(),
+        "pre-release template of the describe format expands to nothing",
+    );
+    assert_to_string(
+        Version::from_git_describe_with("v2.0.0-rc.1-dirty", &format).unwrap_err(),
+        "pre-release template of the describe format expands to nothing",
+    );
+
+    let format = DescribeFormat::new().pre("dev.{count");
+    assert_to_string(
+        Version::from_git_describe_with("v1.2.3-1-g1234", &format).unwrap_err(),
+        "unknown placeholder or unmatched brace at byte 4 of template",
+    );
+
+    let format = DescribeFormat::new().build("git_{hash}");
+    assert_to_string(
+        Version::from_git_describe_with("v1.2.3-1-g1234", &format).unwrap_err(),
+        "unexpected character in build metadata",
+    );
+}
@@ -0,0 +1,49 @@
+#![allow(clippy::uninlined_format_args, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{ReqIndex, VersionReq};
+
+#[test]
+fn test_matching_agrees() {
+    let reqs: Vec<VersionReq> = REQ_CORPUS.iter().map(|r| req(r)).collect();
+
+    // Insert enough requirements to exercise both the tree and the unsorted
+    // entries inserted since it was last built.
+    let mut index = ReqIndex::new();
+    for round in 0..10 {
+        for (i, req) in reqs.iter().enumerate() {
+            index.insert((round, i), req);
+        }
+
+        for v in VERSION_CORPUS {
+            let v = version(v);
+            let mut actual: Vec<(usize, usize)> = index.matching(&v).copied().collect();
+            actual.sort_unstable();
+            let mut expected = Vec::new();
+            for round in 0..=round {
+                for (i, req) in reqs.iter().enumerate() {
+                    if req.matches(&v) {
+                        expected.push((round, i));
+                    }
+                }
+            }
+            assert_eq!(actual, expected, "{}", v);
+        }
+    }
+
+    assert_eq!(index.len(), 10 * reqs.len());
+}
+
+#[test]
+fn test_from_iter() {
+    let index: ReqIndex<&str> = [("a", req("^1.2")), ("b", req("~1.3.4")), ("c", req("<1"))]
+        .into_iter()
+        .collect();
+    let mut matching: Vec<&str> = index.matching(&version("1.3.5")).copied().collect();
+    matching.sort_unstable();
+    assert_eq!(matching, ["a", "b"]);
+    assert_eq!(index.matching(&version("2.0.0")).count(), 0);
+    assert_eq!(index.matching(&version("1.3.5-rc.1")).count(), 0);
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "std")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::VersionInterner;
+use std::thread;
+
+#[test]
+fn test_intern() {
+    let mut interner = VersionInterner::new();
+    for len in [9, 10, 127, 128, 300] {
+        let text = "x".repeat(len);
+        let a = interner.intern_prerelease(prerelease(&text));
+        let b = interner.intern_prerelease(prerelease(&text));
+        assert_eq!(a.as_str(), text);
+        assert_eq!(a.as_str().as_ptr(), b.as_str().as_ptr());
+        assert_eq!(a, b);
+        assert_eq!(a, prerelease(&text));
+        assert_eq!(a.clone().as_str().as_ptr(), a.as_str().as_ptr());
+
+        let again = interner.intern_prerelease(a.clone());
+        assert_eq!(again.as_str().as_ptr(), a.as_str().as_ptr());
+        drop(a);
+        assert_eq!(b.as_str(), text);
+    }
+    assert_eq!(interner.len(), 5);
+
+    let short = prerelease("alpha.1");
+    assert_eq!(interner.intern_prerelease(short.clone()), short);
+    assert!(interner.intern_prerelease(prerelease("")).is_empty());
+    assert_eq!(interner.len(), 5);
+}
+
+#[test]
+fn test_version() {
+    let mut interner = VersionInterner::new();
+    let a = interner.intern(version("1.0.0-alpha.20260101+build.5f2e1c0a"));
+    let b = interner.intern(version("2.1.0-alpha.20260101+build.5f2e1c0a"));
+    assert_eq!(a.pre.as_str().as_ptr(), b.pre.as_str().as_ptr());
+    assert_eq!(a.build.as_str().as_ptr(), b.build.as_str().as_ptr());
+    assert_eq!(a.pre, prerelease("alpha.20260101"));
+    assert_eq!(a.build, build_metadata("build.5f2e1c0a"));
+    assert_eq!(a.to_string(), "1.0.0-alpha.20260101+build.5f2e1c0a");
+
+    // The pool keys by string, shared between pre-release and build metadata.
+    let pre = interner.intern_prerelease(prerelease("shared-string"));
+    let build = interner.intern_build(build_metadata("shared-string"));
+    assert_eq!(pre.as_str().as_ptr(), build.as_str().as_ptr());
+
+    // Separate interners do not share.
+    let other = VersionInterner::new().intern(version("1.0.0-alpha.20260101"));
+    assert_ne!(other.pre.as_str().as_ptr(), a.pre.as_str().as_ptr());
+    assert_eq!(other.pre, a.pre);
+}
+
+#[test]
+fn test_drop() {
+    let mut interner = VersionInterner::new();
+    let a = interner.intern(version("1.0.0-alpha.20260101"));
+    let b = a.clone();
+    drop(interner);
+    assert_eq!(a.to_string(), "1.0.0-alpha.20260101");
+    drop(a);
+    assert_eq!(b.to_string(), "1.0.0-alpha.20260101");
+
+    // An interned string can be interned again by another interner.
+    let mut interner = VersionInterner::new();
+    let c = interner.intern(b.clone());
+    assert_eq!(c.pre.as_str().as_ptr(), b.pre.as_str().as_ptr());
+    drop(b);
+    drop(interner);
+    assert_eq!(c.to_string(), "1.0.0-alpha.20260101");
+}
+
+#[test]
+fn test_threads() {
+    let mut interner = VersionInterner::new();
+    let pre = interner.intern_prerelease(prerelease("rc.1.threaded"));
+    drop(interner);
+
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            let pre = pre.clone();
+            thread::spawn(move || {
+                let clones: Vec<_> = (0..100).map(|_| pre.clone()).collect();
+                clones[99].clone()
+            })
+        })
+        .collect();
+    drop(pre);
+    let clones: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+    for pre in &clones {
+        assert_eq!(pre.as_str().as_ptr(), clones[0].as_str().as_ptr());
+        assert_eq!(pre.as_str(), "rc.1.threaded");
+    }
+}
@@ -0,0 +1,93 @@
+#![allow(clippy::uninlined_format_args, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{version, Version};
+use std::panic;
+
+const MSRV: Version = version!("1.68.0");
+const BETA: Version = version!("1.2.3-beta.1+20260101");
+const MAX: Version = version!("18446744073709551615.18446744073709551615.18446744073709551615");
+
+#[test]
+fn test_version() {
+    assert_eq!(MSRV, version("1.68.0"));
+    assert_eq!(BETA, version("1.2.3-beta.1+20260101"));
+    assert_eq!(MAX, Version::new(u64::MAX, u64::MAX, u64::MAX));
+    assert_eq!(BETA.pre.as_str(), "beta.1");
+    assert_eq!(BETA.build.as_str(), "20260101");
+    assert!(MSRV.pre.is_empty());
+    assert!(MSRV.build.is_empty());
+
+    assert_eq!(version!("0.0.0-a"), version("0.0.0-a"));
+    assert_eq!(version!("0.0.0+a-b.c"), version("0.0.0+a-b.c"));
+    assert_eq!(version!("1.2.3-12345678"), version("1.2.3-12345678"));
+
+    let clone = BETA.clone();
+    assert_eq!(clone, BETA);
+    assert_eq!(BETA.to_string(), "1.2.3-beta.1+20260101");
+}
+
+// The const parser is exercised at runtime here, where its panics can be
+// caught, to check that it agrees with Version::parse_inline on the corpus.
+#[test]
+fn test_agrees_with_parse() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for text in VERSION_CORPUS.iter().chain(REQ_CORPUS) {
+        let expected = Version::parse_inline(text);
+        let actual = panic::catch_unwind(|| semver::__private::version(text));
+        match (&expected, &actual) {
+            (Ok(expected), Ok(actual)) => assert_eq!(expected, actual, "{}", text),
+            (Err(expected), Err(payload)) => {
+                // The same message, less the quoted character.
+                let mut expected = expected.to_string();
+                if let Some(start) = expected.find(" '") {
+                    let end = start + expected[start + 2..].find("' ").unwrap() + 3;
+                    expected.replace_range(start..end, "");
+                }
+                let actual = payload.downcast_ref::<String>().unwrap();
+                assert!(actual.starts_with(&expected), "{}: {}", text, actual);
+            }
+            _ => panic!("disagreement on version {:?}", text),
+        }
+    }
+
+    panic::set_hook(hook);
+}
+
+#[test]
+fn test_error_messages() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let version_error = |text: &'static str| {
+        let payload = panic::catch_unwind(|| semver::__private::version(text)).unwrap_err();
+        payload.downcast_ref::<String>().unwrap().clone()
+    };
+
+    let errors = [
+        (version_error(""), "empty string, expected a semver version"),
+        (version_error("1.2.03"), "invalid leading zero in patch version number"),
+        (version_error("1.2"), "unexpected end of input while parsing minor version number"),
+        (version_error("1.2.3-"), "empty identifier segment in pre-release identifier"),
+        (version_error("1.2.3-01"), "invalid leading zero in pre-release identifier"),
+        (version_error("1.2.3 "), "unexpected character after patch version number"),
+        (
+            version_error("1.2.18446744073709551616"),
+            "value of patch version number exceeds u64::MAX",
+        ),
+        (
+            version_error("1.2.3-prerelease"),
+            "pre-release identifier longer than 8 bytes cannot be stored without allocating, use Version::parse",
+        ),
+    ];
+
+    panic::set_hook(hook);
+
+    for (actual, expected) in errors {
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,98 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Comparator, ParseOptions, Version, VersionReq};
+
+#[test]
+fn test_default() {
+    let options = ParseOptions::default();
+    assert_eq!(options, ParseOptions::new());
+    for text in VERSION_CORPUS {
+        assert_eq!(Version::parse_with(text, &options).unwrap(), version(text));
+    }
+    for text in REQ_CORPUS {
+        assert_eq!(VersionReq::parse_with(text, &options).unwrap(), req(text));
+        if let Ok(comparator) = Comparator::parse(text) {
+            assert_eq!(Comparator::parse_with(text, &options).unwrap(), comparator);
+        }
+    }
+
+    let many = vec!["1"; 33].join(",");
+    let error = VersionReq::parse_with(&many, &options).unwrap_err();
+    assert_to_string(
+        error,
+        "version req has more than 32 comparators, the limit set by max_comparators",
+    );
+    let error = VersionReq::parse_with("1.0, *", &options).unwrap_err();
+    assert_to_string(
+        error,
+        "wildcard req (*) must be the only comparator in the version req",
+    );
+}
+
+#[test]
+fn test_limits() {
+    let options = ParseOptions::new()
+        .max_input_len(24)
+        .max_comparators(2)
+        .max_identifiers(2)
+        .max_identifier_len(5);
+
+    assert!(Version::parse_with("1.2.3-alpha.1+b.12345", &options).is_ok());
+    assert!(VersionReq::parse_with(">=1.2.3-rc.1, <2", &options).is_ok());
+    assert!(VersionReq::parse_with("*", &options).is_ok());
+
+    let error = Version::parse_with("1.2.3-alpha+0123456789abcdef", &options).unwrap_err();
+    assert_to_string(
+        error,
+        "input is longer than 24 bytes, the limit set by max_input_len",
+    );
+
+    let error = VersionReq::parse_with("1, 2, 3", &options).unwrap_err();
+    assert_to_string(
+        error,
+        "version req has more than 2 comparators, the limit set by max_comparators",
+    );
+
+    let error = Version::parse_with("1.2.3-a.b.c", &options).unwrap_err();
+    assert_to_string(
+        error,
+        "pre-release identifier has more than 2 dot-separated identifiers, the limit set by max_identifiers",
+    );
+
+    let error = Version::parse_with("1.2.3+build1", &options).unwrap_err();
+    assert_to_string(
+        error,
+        "build metadata has an identifier longer than 5 bytes, the limit set by max_identifier_len",
+    );
+
+    let error = Comparator::parse_with("^1.2.3-alpha.beta12", &options).unwrap_err();
+    assert_to_string(
+        error,
+        "pre-release identifier has an identifier longer than 5 bytes, the limit set by max_identifier_len",
+    );
+
+    let error = VersionReq::parse_with(">1, <1.2.3-a.b.c", &options).unwrap_err();
+    assert_to_string(
+        error,
+        "pre-release identifier has more than 2 dot-separated identifiers, the limit set by max_identifiers",
+    );
+
+    // Syntax errors are reported as usual.
+    let error = Version::parse_with("1.2", &options).unwrap_err();
+    assert_to_string(
+        error,
+        "unexpected end of input while parsing minor version number",
+    );
+}
+
+#[test]
+fn test_more_comparators() {
+    let options = ParseOptions::new().max_comparators(100);
+    let many = vec![">=1"; 100].join(", ");
+    let req = VersionReq::parse_with(&many, &options).unwrap();
+    assert_eq!(req.comparators.len(), 100);
+    assert!(VersionReq::parse(&many).is_err());
+}
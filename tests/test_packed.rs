@@ -0,0 +1,114 @@
+#![allow(clippy::uninlined_format_args, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{PackedVersion, PackedVersion128, Version};
+use std::convert::TryFrom;
+
+#[test]
+fn test_new() {
+    const V: PackedVersion = match PackedVersion::new(1, 2, 3) {
+        Some(version) => version,
+        None => panic!(),
+    };
+    assert_eq!((V.major(), V.minor(), V.patch()), (1, 2, 3));
+    assert!(!V.has_prerelease());
+    assert_eq!(V.to_version(), Some(version("1.2.3")));
+    assert_eq!(PackedVersion::from_bits(V.to_bits()), Some(V));
+    assert_eq!(
+        format!("{:?}", V),
+        "PackedVersion { major: 1, minor: 2, patch: 3 }",
+    );
+
+    assert_eq!(PackedVersion::MAX_FIELD, 2_097_151);
+    assert!(PackedVersion::new(2_097_151, 2_097_151, 2_097_151).is_some());
+    assert!(PackedVersion::new(0, 2_097_152, 0).is_none());
+    assert_eq!(PackedVersion128::MAX_FIELD, (1 << 42) - 1);
+    assert!(PackedVersion128::new(0, 0, 20_240_101).is_some());
+    assert!(PackedVersion128::from_bits(1 << 127).is_none());
+}
+
+#[test]
+fn test_conversion() {
+    let packed = PackedVersion::try_from(&version("1.0.9")).unwrap();
+    assert_eq!(Version::try_from(packed).unwrap(), version("1.0.9"));
+
+    let error = PackedVersion::try_from(&version("1.0.0-rc.1")).unwrap_err();
+    assert_to_string(error, "pre-release cannot be stored in a packed version");
+
+    let error = PackedVersion128::try_from(&version("1.0.0+abc")).unwrap_err();
+    assert_to_string(error, "build metadata cannot be stored in a packed version");
+
+    let error = PackedVersion::from_version(&version("1.20240101.0")).unwrap_err();
+    assert_to_string(
+        error,
+        "value of minor version number does not fit in 21 bits",
+    );
+
+    let error = PackedVersion128::from_version(&version("18446744073709551615.0.0")).unwrap_err();
+    assert_to_string(
+        error,
+        "value of major version number does not fit in 42 bits",
+    );
+
+    let packed = PackedVersion::from_version(&version("1.0.0-rc.1+abc")).unwrap();
+    assert!(packed.has_prerelease());
+    assert_eq!(packed.to_version(), None);
+    assert_eq!(packed.to_version_ref(), None);
+    assert_eq!(
+        format!("{:?}", packed),
+        "PackedVersion { major: 1, minor: 0, patch: 0, has_prerelease: true }",
+    );
+    let error = Version::try_from(packed).unwrap_err();
+    assert_to_string(error, "pre-release cannot be stored in a packed version");
+
+    let packed = PackedVersion128::from_version(&version("1.0.0+abc")).unwrap();
+    assert_eq!(packed.to_version(), Some(version("1.0.0")));
+}
+
+#[test]
+fn test_order() {
+    // The corpus includes numbers too large to pack, which are skipped.
+    let versions: Vec<(Version, PackedVersion, PackedVersion128)> = VERSION_CORPUS
+        .iter()
+        .map(|v| version(v))
+        .filter_map(|v| {
+            let packed = PackedVersion::from_version(&v).ok()?;
+            let wide = PackedVersion128::from_version(&v).ok()?;
+            Some((v, packed, wide))
+        })
+        .collect();
+    assert!(versions.len() > 30);
+
+    for (a, pa, wa) in &versions {
+        for (b, pb, wb) in &versions {
+            let same_release = (a.major, a.minor, a.patch) == (b.major, b.minor, b.patch);
+            if same_release && !a.pre.is_empty() && !b.pre.is_empty() {
+                assert_eq!(pa, pb);
+                assert_eq!(wa, wb);
+            } else {
+                assert_eq!(pa.cmp(pb), a.cmp_precedence(b), "{} vs {}", a, b);
+                assert_eq!(wa.cmp(wb), a.cmp_precedence(b), "{} vs {}", a, b);
+            }
+            assert_eq!(pa.to_bits().cmp(&pb.to_bits()), pa.cmp(pb));
+        }
+    }
+}
+
+#[test]
+fn test_matches() {
+    for r in REQ_CORPUS {
+        let req = req(r);
+        for v in VERSION_CORPUS {
+            let version = version(v);
+            let Ok(packed) = PackedVersion::from_version(&version) else {
+                continue;
+            };
+            match packed.to_version_ref() {
+                Some(packed) => assert_eq!(req.matches_ref(&packed), req.matches(&version)),
+                None => assert!(!version.pre.is_empty()),
+            }
+        }
+    }
+}
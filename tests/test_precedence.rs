@@ -0,0 +1,81 @@
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Precedence, Version, VersionRef};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_precedence() {
+    let a = Precedence(version("1.0.0+a"));
+    let b = Precedence(version("1.0.0+b.1"));
+    assert_eq!(a, b);
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(a, Precedence(version("1.0.0-rc.1+a")));
+    assert!(Precedence(version("1.0.0-rc.1+z")) < a);
+
+    let mut map = BTreeMap::new();
+    map.insert(a.clone(), "first");
+    map.insert(b, "second");
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&Precedence(version("1.0.0"))], "second");
+
+    let borrowed: HashSet<_> = ["1.0.0+a", "1.0.0+b", "2.0.0"]
+        .iter()
+        .map(|v| Precedence(VersionRef::parse(v).unwrap()))
+        .collect();
+    assert_eq!(borrowed.len(), 2);
+
+    for x in VERSION_CORPUS {
+        for y in VERSION_CORPUS {
+            let (x, y) = (version(x), version(y));
+            let (px, py) = (Precedence(x.clone()), Precedence(y.clone()));
+            assert_eq!(px.cmp(&py), x.cmp_precedence(&y));
+            assert_eq!(px == py, hash(&px) == hash(&py));
+            let rx = Precedence(VersionRef::from(&x));
+            assert_eq!(hash(&px), hash(&rx));
+            assert_eq!(rx.cmp(&Precedence(VersionRef::from(&y))), px.cmp(&py));
+        }
+    }
+}
+
+#[test]
+fn test_dedup() {
+    let mut versions = [
+        "1.0.0+b",
+        "1.0.0",
+        "0.9.0",
+        "1.0.0+a",
+        "1.0.0-rc.1",
+        "1.0.0-rc.1+x",
+        "2.0.0",
+    ]
+    .map(version);
+    versions.sort();
+    let len = semver::dedup_by_precedence(&mut versions);
+    assert_eq!(
+        versions[..len],
+        [
+            version("0.9.0"),
+            version("1.0.0-rc.1"),
+            version("1.0.0"),
+            version("2.0.0"),
+        ],
+    );
+
+    let mut versions: Vec<Version> = Vec::new();
+    assert_eq!(semver::dedup_by_precedence(&mut versions), 0);
+
+    let mut versions = [version("1.0.0+a"), version("1.0.0+b")];
+    assert_eq!(semver::dedup_by_precedence(&mut versions), 1);
+    assert_eq!(versions[0], version("1.0.0+a"));
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "proptest")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use proptest::prelude::*;
+use semver::{BuildMetadata, Comparator, Prerelease, Version, VersionReq};
+
+proptest! {
+    #[test]
+    fn test_roundtrip(
+        version in any::<Version>(),
+        req in any::<VersionReq>(),
+        comparator in any::<Comparator>(),
+        pre in any::<Prerelease>(),
+        build in any::<BuildMetadata>(),
+    ) {
+        prop_assert_eq!(version.to_string().parse::<Version>().unwrap(), version);
+        prop_assert_eq!(req.to_string().parse::<VersionReq>().unwrap(), req);
+        prop_assert_eq!(comparator.to_string().parse::<Comparator>().unwrap(), comparator);
+        prop_assert_eq!(Prerelease::new(pre.as_str()).unwrap(), pre);
+        prop_assert_eq!(BuildMetadata::new(build.as_str()).unwrap(), build);
+    }
+
+    #[test]
+    fn test_arbitrary_req((req, version) in any::<VersionReq>().prop_flat_map(|req| {
+        let strategy = semver::proptest::not_matching(&req);
+        (Just(req), strategy)
+    })) {
+        prop_assert!(!req.matches(&version), "{} {}", req, version);
+    }
+}
+
+#[test]
+fn test_corpus() {
+    let mut runner = proptest::test_runner::TestRunner::deterministic();
+    for r in REQ_CORPUS {
+        let r = req(r);
+        // Nothing is greater than u64::MAX.0.0.
+        if r.to_string() != ">18446744073709551615" {
+            runner
+                .run(&semver::proptest::matching(&r), |v| {
+                    prop_assert!(r.matches(&v), "{} {}", r, v);
+                    Ok(())
+                })
+                .unwrap();
+        }
+        runner
+            .run(&semver::proptest::not_matching(&r), |v| {
+                prop_assert!(!r.matches(&v), "{} {}", r, v);
+                Ok(())
+            })
+            .unwrap();
+    }
+}
+
+#[test]
+fn test_prerelease_only() {
+    // Only pre-releases of 1.2.3 match.
+    let r = req(">=1.2.3-alpha, <1.2.3");
+    let mut runner = proptest::test_runner::TestRunner::deterministic();
+    runner
+        .run(&semver::proptest::matching(&r), |v| {
+            prop_assert!(r.matches(&v), "{}", v);
+            prop_assert_eq!((v.major, v.minor, v.patch), (1, 2, 3));
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_unsatisfiable() {
+    let r = req(">1, <1");
+    let mut runner = proptest::test_runner::TestRunner::deterministic();
+    let result = runner.run(&semver::proptest::matching(&r), |v| {
+        prop_assert!(r.matches(&v), "{}", v);
+        Ok(())
+    });
+    assert!(result.is_err());
+}
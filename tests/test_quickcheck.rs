@@ -0,0 +1,74 @@
+#![cfg(feature = "quickcheck")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use quickcheck::{Arbitrary, Gen, QuickCheck};
+use semver::{BuildMetadata, Comparator, Prerelease, Version, VersionReq};
+
+#[test]
+fn test_roundtrip() {
+    fn prop(
+        version: Version,
+        req: VersionReq,
+        comparator: Comparator,
+        pre: Prerelease,
+        build: BuildMetadata,
+    ) -> bool {
+        version.to_string().parse::<Version>().unwrap() == version
+            && req.to_string().parse::<VersionReq>().unwrap() == req
+            && comparator.to_string().parse::<Comparator>().unwrap() == comparator
+            && Prerelease::new(pre.as_str()).unwrap() == pre
+            && BuildMetadata::new(build.as_str()).unwrap() == build
+    }
+    QuickCheck::new()
+        .quickcheck(prop as fn(Version, VersionReq, Comparator, Prerelease, BuildMetadata) -> bool);
+}
+
+#[test]
+fn test_shrink() {
+    let v = version("3.2.1-rc.1+build");
+    assert!(v.shrink().all(|shrunk| shrunk != v));
+    assert!(v.shrink().any(|shrunk| shrunk == version("3.2.1-rc.1")));
+    assert!(v.shrink().any(|shrunk| shrunk == version("3.2.1+build")));
+}
+
+#[test]
+fn test_corpus() {
+    let mut g = Gen::new(10);
+    for r in REQ_CORPUS {
+        let r = req(r);
+        for _ in 0..100 {
+            if r.to_string() != ">18446744073709551615" {
+                let v = semver::quickcheck::matching(&mut g, &r).unwrap();
+                assert!(r.matches(&v), "{} {}", r, v);
+            }
+            let v = semver::quickcheck::not_matching(&mut g, &r).unwrap();
+            assert!(!r.matches(&v), "{} {}", r, v);
+        }
+    }
+}
+
+#[test]
+fn test_arbitrary_req() {
+    let mut g = Gen::new(10);
+    for _ in 0..1000 {
+        let r = VersionReq::arbitrary(&mut g);
+        if let Some(v) = semver::quickcheck::matching(&mut g, &r) {
+            assert!(r.matches(&v), "{} {}", r, v);
+        }
+        let v = semver::quickcheck::not_matching(&mut g, &r).unwrap();
+        assert!(!r.matches(&v), "{} {}", r, v);
+    }
+}
+
+#[test]
+fn test_unsatisfiable() {
+    let mut g = Gen::new(10);
+    assert_eq!(semver::quickcheck::matching(&mut g, &req(">1, <1")), None);
+    assert_eq!(
+        semver::quickcheck::matching(&mut g, &req(">18446744073709551615")),
+        None,
+    );
+}
@@ -0,0 +1,159 @@
+#![cfg(feature = "schemars")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use regex::Regex;
+use schemars::{schema_for, JsonSchema};
+use semver::{BuildMetadata, Comparator, Prerelease, Version, VersionReq};
+use std::str::FromStr;
+
+fn pattern<T: JsonSchema>() -> Regex {
+    let schema = schema_for!(T);
+    assert_eq!(schema.get("type").unwrap(), "string");
+    Regex::new(schema.get("pattern").unwrap().as_str().unwrap()).unwrap()
+}
+
+struct Patterns {
+    version: Regex,
+    req: Regex,
+    comparator: Regex,
+    pre: Regex,
+    build: Regex,
+}
+
+impl Patterns {
+    fn new() -> Self {
+        Patterns {
+            version: pattern::<Version>(),
+            req: pattern::<VersionReq>(),
+            comparator: pattern::<Comparator>(),
+            pre: pattern::<Prerelease>(),
+            build: pattern::<BuildMetadata>(),
+        }
+    }
+
+    #[track_caller]
+    fn assert_agree(&self, text: &str) {
+        fn check<T: FromStr>(regex: &Regex, text: &str, what: &str) {
+            assert_eq!(
+                regex.is_match(text),
+                T::from_str(text).is_ok(),
+                "{} {:?}",
+                what,
+                text,
+            );
+        }
+        check::<Version>(&self.version, text, "version");
+        check::<VersionReq>(&self.req, text, "req");
+        check::<Comparator>(&self.comparator, text, "comparator");
+        check::<Prerelease>(&self.pre, text, "pre");
+        check::<BuildMetadata>(&self.build, text, "build");
+    }
+}
+
+#[test]
+fn test_corpus() {
+    let patterns = Patterns::new();
+    for text in VERSION_CORPUS.iter().chain(REQ_CORPUS) {
+        patterns.assert_agree(text);
+    }
+    for text in [
+        "",
+        " ",
+        "x",
+        " X ",
+        "*, 1",
+        "1.*.2",
+        "1.*.*",
+        "1.x.X",
+        ">= 1.2 , < 2",
+        "1.2.3-",
+        "1.2.3+",
+        "1.2.3-01",
+        "1.2.3-0a",
+        "1.2.3+01",
+        "1.2.3-a..b",
+        "1.2-alpha",
+        "01.2.3",
+        "1.2.3 ",
+        " 1.2.3",
+        "18446744073709551616.0.0",
+        "99999999999999999999.0.0",
+        "10000000000000000000.0.0",
+        ">=18446744073709551616",
+        "1.2.3-alpha_1",
+        "a.b-c",
+        "-",
+        "=>1",
+        "^1.2.3+build, ~1",
+        &["1"; 32].join(", "),
+        &["1"; 33].join(", "),
+    ] {
+        patterns.assert_agree(text);
+    }
+}
+
+#[test]
+fn test_random() {
+    const NUMBERS: &[&str] = &[
+        "0",
+        "1",
+        "01",
+        "23",
+        "18446744073709551615",
+        "18446744073709551616",
+    ];
+    const WILDCARDS: &[&str] = &["*", "x", "X"];
+    const OPS: &[&str] = &["", "", "=", ">", ">=", "<", "<=", "~", "^", "=>"];
+    const IDENTIFIERS: &[&str] = &["0", "1", "01", "a", "rc", "0a", "A-", "-", "", "_"];
+    const SEPARATORS: &[&str] = &["", " ", "  ", ",", ", ", " , "];
+
+    let patterns = Patterns::new();
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move |n: usize| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state % n as u64) as usize
+    };
+    let mut pick = |choices: &[&'static str]| choices[next(choices.len())];
+
+    let mut text = String::new();
+    for _ in 0..50_000 {
+        text.clear();
+        for i in 0..1 + pick(&["0", "0", "0", "1", "2"]).parse::<usize>().unwrap() {
+            if i > 0 {
+                text.push_str(pick(SEPARATORS));
+            }
+            text.push_str(pick(&["", "", " "]));
+            text.push_str(pick(OPS));
+            text.push_str(pick(&["", "", " "]));
+            text.push_str(pick(NUMBERS));
+            let parts = pick(&["0", "1", "2", "2", "2"]).parse::<usize>().unwrap();
+            for _ in 0..parts {
+                text.push('.');
+                let choices = if pick(&["n", "n", "n", "w"]) == "n" {
+                    NUMBERS
+                } else {
+                    WILDCARDS
+                };
+                text.push_str(pick(choices));
+            }
+            for prefix in ["-", "+"] {
+                if pick(&["", "", prefix]) == prefix {
+                    text.push_str(prefix);
+                    for j in 0..1 + pick(&["0", "0", "1", "2"]).parse::<usize>().unwrap() {
+                        if j > 0 {
+                            text.push('.');
+                        }
+                        text.push_str(pick(IDENTIFIERS));
+                    }
+                }
+            }
+        }
+        text.push_str(pick(&["", "", "", " ", ","]));
+        patterns.assert_agree(&text);
+    }
+}
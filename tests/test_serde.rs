@@ -0,0 +1,135 @@
+#![cfg(feature = "serde")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use bincode::Options as _;
+use semver::{BuildMetadata, Op, Version, VersionReq};
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+#[test]
+fn test_string_form() {
+    assert_tokens(&prerelease("rc.1"), &[Token::Str("rc.1")]);
+    assert_tokens(&build_metadata("g1a2b3c"), &[Token::Str("g1a2b3c")]);
+    assert_tokens(&BuildMetadata::EMPTY, &[Token::Str("")]);
+    assert_tokens(&Op::GreaterEq, &[Token::Str(">=")]);
+    assert_tokens(&Op::Wildcard, &[Token::Str("*")]);
+
+    assert_de_tokens_error::<Op>(
+        &[Token::Str("=>")],
+        "invalid value: string \"=>\", expected semver comparison operator",
+    );
+    assert_de_tokens_error::<BuildMetadata>(
+        &[Token::Str("a..b")],
+        "empty identifier segment in build metadata",
+    );
+}
+
+fn to_json(serialize: impl FnOnce(&mut serde_json::Serializer<&mut Vec<u8>>)) -> String {
+    let mut out = Vec::new();
+    serialize(&mut serde_json::Serializer::new(&mut out));
+    String::from_utf8(out).unwrap()
+}
+
+fn structured(json: &str) -> Result<Version, serde_json::Error> {
+    semver::serde::structured::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+fn tuple(json: &str) -> Result<Version, serde_json::Error> {
+    semver::serde::tuple::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+fn comparators(json: &str) -> Result<VersionReq, serde_json::Error> {
+    semver::serde::comparators::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+#[test]
+fn test_structured() {
+    let v = version("1.2.3-rc.1+build.5");
+    let json = to_json(|ser| semver::serde::structured::serialize(&v, ser).unwrap());
+    assert_eq!(
+        json,
+        r#"{"major":1,"minor":2,"patch":3,"pre":"rc.1","build":"build.5"}"#,
+    );
+    assert_eq!(structured(&json).unwrap(), v);
+
+    let v = structured(r#"{"patch":3,"minor":2,"major":1}"#).unwrap();
+    assert_eq!(v, version("1.2.3"));
+
+    let err = structured(r#"{"major":1,"minor":2}"#).unwrap_err();
+    assert_to_string(err, "missing field `patch` at line 1 column 21");
+    let err = structured(r#"{"major":1,"major":1}"#).unwrap_err();
+    assert_to_string(err, "duplicate field `major` at line 1 column 21");
+    let err = structured(r#"{"major":1,"minor":2,"patch":3,"pre":"01"}"#).unwrap_err();
+    assert_to_string(
+        err,
+        "invalid leading zero in pre-release identifier at line 1 column 41",
+    );
+    let err = structured(r#"{"major":1,"minor":2,"patch":3,"version":""}"#).unwrap_err();
+    assert_to_string(
+        err,
+        "unknown field `version`, expected one of `major`, `minor`, `patch`, `pre`, `build` at line 1 column 40",
+    );
+}
+
+#[test]
+fn test_tuple() {
+    let v = version("1.2.3-rc.1");
+    let json = to_json(|ser| semver::serde::tuple::serialize(&v, ser).unwrap());
+    assert_eq!(json, r#"[1,2,3,"rc.1",""]"#);
+    assert_eq!(tuple(&json).unwrap(), v);
+    assert_eq!(tuple(r#"[1,2,3,"rc.1"]"#).unwrap(), v);
+    assert_eq!(tuple("[1,2,3]").unwrap(), version("1.2.3"));
+
+    let err = tuple("[1,2]").unwrap_err();
+    assert_to_string(
+        err,
+        "invalid length 2, expected semver version tuple at line 1 column 5",
+    );
+}
+
+#[test]
+fn test_comparators() {
+    let r = req(">=1.2.3, <1.8.0");
+    let json = to_json(|ser| semver::serde::comparators::serialize(&r, ser).unwrap());
+    assert_eq!(json, r#"[">=1.2.3","<1.8.0"]"#);
+    assert_eq!(comparators(&json).unwrap(), r);
+
+    assert_eq!(comparators("[]").unwrap(), VersionReq::STAR);
+    assert_eq!(comparators(r#"["1.*"]"#).unwrap(), req("1.*"));
+
+    let err = comparators(r#"[">=1.2.3, <1.8.0"]"#).unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character ',' after patch version number at line 1 column 18",
+    );
+
+    let json = format!("[{}]", vec!["\"1\""; 33].join(","));
+    let err = comparators(&json).unwrap_err();
+    assert_to_string(
+        err,
+        "excessive number of version comparators at line 1 column 133",
+    );
+}
+
+#[test]
+fn test_bincode() {
+    // Non-self-describing formats deserialize structs from a sequence.
+    let options = || bincode::DefaultOptions::new().with_fixint_encoding();
+    for v in VERSION_CORPUS {
+        let v = version(v);
+
+        let mut bytes = Vec::new();
+        let mut ser = bincode::Serializer::new(&mut bytes, options());
+        semver::serde::structured::serialize(&v, &mut ser).unwrap();
+        let mut de = bincode::Deserializer::from_slice(&bytes, options());
+        assert_eq!(semver::serde::structured::deserialize(&mut de).unwrap(), v);
+
+        let mut bytes = Vec::new();
+        let mut ser = bincode::Serializer::new(&mut bytes, options());
+        semver::serde::tuple::serialize(&v, &mut ser).unwrap();
+        let mut de = bincode::Deserializer::from_slice(&bytes, options());
+        assert_eq!(semver::serde::tuple::deserialize(&mut de).unwrap(), v);
+    }
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "serde")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Comparator, Version, VersionReq};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Token};
+
+// Stands in for a field with `#[serde(with = "semver::serde::compact")]`.
+#[derive(PartialEq, Debug)]
+struct With<T>(T);
+
+impl<T> Serialize for With<T>
+where
+    T: semver::serde::compact::Compact + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        semver::serde::compact::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for With<T>
+where
+    T: semver::serde::compact::Compact + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        semver::serde::compact::deserialize(deserializer).map(With)
+    }
+}
+
+fn options() -> impl bincode::Options {
+    use bincode::Options as _;
+    bincode::DefaultOptions::new().with_fixint_encoding()
+}
+
+fn roundtrip<T>(value: &T) -> (T, usize)
+where
+    T: semver::serde::compact::Compact + Serialize + for<'de> Deserialize<'de>,
+{
+    let mut bytes = Vec::new();
+    semver::serde::compact::serialize(value, &mut bincode::Serializer::new(&mut bytes, options()))
+        .unwrap();
+    let mut deserializer = bincode::Deserializer::from_slice(&bytes, options());
+    let decoded = semver::serde::compact::deserialize(&mut deserializer).unwrap();
+    (decoded, bytes.len())
+}
+
+#[test]
+fn test_version() {
+    assert_tokens(
+        &With(version("1.0.0-rc.1")).compact(),
+        &[Token::Bytes(b"\x01\x00\x00\x04rc.1\x00")],
+    );
+    assert_tokens(
+        &With(version("300.0.0+build.5")).compact(),
+        &[Token::Bytes(b"\xAC\x02\x00\x00\x00\x07build.5")],
+    );
+    assert_tokens(
+        &With(version("18446744073709551615.0.0")).compact(),
+        &[Token::Bytes(
+            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x01\x00\x00\x00\x00",
+        )],
+    );
+
+    // Human readable formats are unaffected.
+    assert_tokens(
+        &With(version("1.0.0-rc.1")).readable(),
+        &[Token::Str("1.0.0-rc.1")],
+    );
+}
+
+#[test]
+fn test_comparator() {
+    assert_tokens(
+        &With(comparator(">=1.2")).compact(),
+        &[Token::Bytes(b"\x12\x01\x02\x00")],
+    );
+    assert_tokens(
+        &With(comparator("^1.2.3-beta")).compact(),
+        &[Token::Bytes(b"\x36\x01\x02\x03\x04beta")],
+    );
+    assert_tokens(
+        &With(comparator("1.*")).compact(),
+        &[Token::Bytes(b"\x07\x01\x00")],
+    );
+}
+
+#[test]
+fn test_req() {
+    assert_tokens(&With(req("*")).compact(), &[Token::Bytes(b"\x00")]);
+    assert_tokens(
+        &With(req(">=1.2, <2")).compact(),
+        &[Token::Bytes(b"\x02\x12\x01\x02\x00\x03\x02\x00")],
+    );
+}
+
+#[test]
+fn test_bincode() {
+    for v in VERSION_CORPUS {
+        let v = version(v);
+        let (decoded, len) = roundtrip(&v);
+        assert_eq!(decoded, v);
+        assert!(len <= 8 + v.to_string().len());
+    }
+    for r in REQ_CORPUS {
+        let r = req(r);
+        assert_eq!(roundtrip(&r).0, r);
+        for cmp in &r.comparators {
+            assert_eq!(roundtrip(cmp).0, *cmp);
+        }
+    }
+
+    // Without the adapter, the default impls still write strings.
+    let v = version("1.0.0-rc.1");
+    let bytes = bincode::serialize(&v).unwrap();
+    assert_eq!(bytes, b"\x0A\x00\x00\x00\x00\x00\x00\x001.0.0-rc.1");
+}
+
+#[test]
+fn test_seq() {
+    serde_test::assert_de_tokens(
+        &With(version("1.2.3")).compact(),
+        &[
+            Token::Seq { len: Some(5) },
+            Token::U8(1),
+            Token::U8(2),
+            Token::U8(3),
+            Token::U8(0),
+            Token::U8(0),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn test_invalid() {
+    let invalid = "invalid compact binary encoding";
+
+    // Truncated.
+    assert_de_tokens_error::<Compact<With<Version>>>(&[Token::Bytes(b"\x01\x00\x00")], invalid);
+    // Trailing bytes.
+    assert_de_tokens_error::<Compact<With<Version>>>(
+        &[Token::Bytes(b"\x01\x00\x00\x00\x00\x00")],
+        invalid,
+    );
+    // Non-minimal varint.
+    assert_de_tokens_error::<Compact<With<Version>>>(
+        &[Token::Bytes(b"\x81\x00\x00\x00\x00\x00")],
+        invalid,
+    );
+    // Overflowing varint.
+    assert_de_tokens_error::<Compact<With<Version>>>(
+        &[Token::Bytes(
+            b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x02\x00\x00\x00\x00",
+        )],
+        invalid,
+    );
+    // Invalid pre-release.
+    assert_de_tokens_error::<Compact<With<Version>>>(
+        &[Token::Bytes(b"\x01\x00\x00\x02a_\x00")],
+        "unexpected character in pre-release identifier",
+    );
+    // Unknown operator.
+    assert_de_tokens_error::<Compact<With<Comparator>>>(&[Token::Bytes(b"\x08\x01\x00")], invalid);
+    // Patch without minor.
+    assert_de_tokens_error::<Compact<With<Comparator>>>(
+        &[Token::Bytes(b"\x20\x01\x02\x00")],
+        invalid,
+    );
+    // Too many comparators.
+    assert_de_tokens_error::<Compact<With<VersionReq>>>(
+        &[Token::Bytes(b"\x21")],
+        "excessive number of version comparators",
+    );
+    // Not bytes.
+    assert_de_tokens_error::<Compact<With<VersionReq>>>(
+        &[Token::U8(1)],
+        "invalid type: integer `1`, expected compact semver version requirement",
+    );
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "serde")]
+#![allow(clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Version, VersionReq};
+
+fn lenient_version(json: &str) -> Result<Version, serde_json::Error> {
+    semver::serde::lenient::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+fn lenient_req(json: &str) -> Result<VersionReq, serde_json::Error> {
+    semver::serde::lenient::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+fn floats_version(json: &str) -> Result<Version, serde_json::Error> {
+    semver::serde::lenient::floats::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+fn floats_req(json: &str) -> Result<VersionReq, serde_json::Error> {
+    semver::serde::lenient::floats::deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+#[test]
+fn test_version() {
+    assert_eq!(lenient_version("2").unwrap(), version("2.0.0"));
+    assert_eq!(lenient_version("1.0").unwrap(), version("1.0.0"));
+    assert_eq!(lenient_version("0.00").unwrap(), version("0.0.0"));
+    assert_eq!(
+        lenient_version("1000000000000000.0").unwrap(),
+        version("1000000000000000.0.0"),
+    );
+    assert_eq!(lenient_version(r#""1""#).unwrap(), version("1.0.0"));
+    assert_eq!(lenient_version(r#""1.10""#).unwrap(), version("1.10.0"));
+    assert_eq!(
+        lenient_version(r#""1.2.3-rc.1+build""#).unwrap(),
+        version("1.2.3-rc.1+build"),
+    );
+
+    let err = lenient_version(r#"">=1.2""#).unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character '>' while parsing major version number at line 1 column 7",
+    );
+    let err = lenient_version(r#""1.*""#).unwrap_err();
+    assert_to_string(
+        err,
+        "unexpected character '*' while parsing minor version number at line 1 column 5",
+    );
+    let err = lenient_version("-1").unwrap_err();
+    assert_to_string(
+        err,
+        "invalid value: integer `-1`, expected semver version or number at line 1 column 2",
+    );
+    let err = lenient_version("1.23456789012345678").unwrap_err();
+    assert_to_string(
+        err,
+        "floating point number 1.2345678901234567 may have lost precision, write the version as a string instead at line 1 column 19",
+    );
+    let err = lenient_version("100000000000000000000.0").unwrap_err();
+    assert_to_string(
+        err,
+        "floating point number 100000000000000000000 may have lost precision, write the version as a string instead at line 1 column 23",
+    );
+    let err = lenient_version("1.10").unwrap_err();
+    assert_to_string(
+        err,
+        "floating point number 1.1 is ambiguous, it may have been written with trailing zeros such as 1.10; write the version as a string instead at line 1 column 4",
+    );
+}
+
+#[test]
+fn test_floats() {
+    assert_eq!(floats_version("1.2").unwrap(), version("1.2.0"));
+    assert_eq!(floats_version("1.0").unwrap(), version("1.0.0"));
+    assert_eq!(floats_version("0.25").unwrap(), version("0.25.0"));
+    assert_eq!(floats_version("1.10").unwrap(), version("1.1.0"));
+    assert_eq!(floats_version(r#""1.10""#).unwrap(), version("1.10.0"));
+    assert_eq!(floats_req("1.70").unwrap(), req("^1.7"));
+    assert_eq!(floats_req("0.0").unwrap(), req("^0.0"));
+
+    let err = floats_version("0.05").unwrap_err();
+    assert_to_string(
+        err,
+        "invalid leading zero in minor version number at line 1 column 4",
+    );
+    let err = floats_version("1.23456789012345678").unwrap_err();
+    assert_to_string(
+        err,
+        "floating point number 1.2345678901234567 may have lost precision, write the version as a string instead at line 1 column 19",
+    );
+}
+
+#[test]
+fn test_req() {
+    assert_eq!(lenient_req("1").unwrap(), req("^1"));
+    assert_eq!(lenient_req("0.0").unwrap(), req("^0.0"));
+    assert!(lenient_req("1.70").is_err());
+    assert_eq!(lenient_req(r#""1.70""#).unwrap(), req("^1.70"));
+    assert_eq!(lenient_req(r#"">=1.2, <2""#).unwrap(), req(">=1.2, <2"));
+
+    let err = lenient_req("true").unwrap_err();
+    assert_to_string(
+        err,
+        "invalid type: boolean `true`, expected semver version requirement or number at line 1 column 4",
+    );
+}
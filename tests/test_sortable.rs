@@ -0,0 +1,135 @@
+#![allow(clippy::uninlined_format_args, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::Version;
+
+#[test]
+fn test_order() {
+    let mut versions: Vec<Version> = VERSION_CORPUS.iter().map(|v| version(v)).collect();
+    versions.extend(
+        [
+            "0.0.0",
+            "0.0.1",
+            "0.0.255",
+            "0.0.256",
+            "0.1.0",
+            "1.0.0-0",
+            "1.0.0-0.0",
+            "1.0.0-1",
+            "1.0.0-9",
+            "1.0.0-10",
+            "1.0.0-A",
+            "1.0.0-a",
+            "1.0.0-a-",
+            "1.0.0-a.0",
+            "1.0.0-a.b",
+            "1.0.0-aa",
+            "1.0.0",
+            "1.0.0+0",
+            "1.0.0+00",
+            "1.0.0+1",
+            "1.0.0+01",
+            "1.0.0+001",
+            "1.0.0+2",
+            "1.0.0+10",
+            "1.0.0+a",
+            "1.0.0+a.1",
+            "18446744073709551615.18446744073709551615.18446744073709551615",
+        ]
+        .iter()
+        .map(|v| version(v)),
+    );
+
+    for a in &versions {
+        for b in &versions {
+            assert_eq!(
+                a.to_sortable_bytes().cmp(&b.to_sortable_bytes()),
+                a.cmp(b),
+                "{} {}",
+                a,
+                b,
+            );
+            assert_eq!(
+                a.to_precedence_bytes().cmp(&b.to_precedence_bytes()),
+                a.cmp_precedence(b),
+                "{} {}",
+                a,
+                b,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_roundtrip() {
+    for v in VERSION_CORPUS
+        .iter()
+        .chain(&["1.0.0+0.00.000.10.010", "0.0.0-0"])
+    {
+        let v = version(v);
+        let decoded = Version::from_sortable_bytes(&v.to_sortable_bytes()).unwrap();
+        assert_eq!(decoded, v);
+        assert_eq!(decoded.build, v.build);
+
+        let decoded = Version::from_sortable_bytes(&v.to_precedence_bytes()).unwrap();
+        assert_eq!(decoded.cmp_precedence(&v), std::cmp::Ordering::Equal);
+        assert!(decoded.build.is_empty());
+    }
+}
+
+#[test]
+fn test_invalid() {
+    let bytes = version("1.2.3-rc.1+build.5").to_sortable_bytes();
+    for len in 0..bytes.len() {
+        let truncated = &bytes[..len];
+        // Dropping the entire build metadata produces the precedence encoding.
+        if truncated == version("1.2.3-rc.1").to_precedence_bytes() {
+            continue;
+        }
+        let err = Version::from_sortable_bytes(truncated).unwrap_err();
+        assert_to_string(err, "invalid sortable byte encoding of a version");
+    }
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(Version::from_sortable_bytes(&trailing).is_err());
+
+    // Non-minimal number.
+    assert!(Version::from_sortable_bytes(&[2, 0, 1, 0, 0, 2, 0]).is_err());
+    // Numeric identifier encoded as alphanumeric.
+    assert!(Version::from_sortable_bytes(&[0, 0, 0, 1, 2, b'1', 0, 0]).is_err());
+    // Empty pre-release list.
+    assert!(Version::from_sortable_bytes(&[0, 0, 0, 1, 0]).is_err());
+    // Disallowed character.
+    assert!(Version::from_sortable_bytes(&[0, 0, 0, 1, 2, b'_', 0, 0]).is_err());
+    // Two identifiers encoded as one alphanumeric identifier.
+    assert!(Version::from_sortable_bytes(&[0, 0, 0, 1, 2, b'a', b'.', b'b', 0, 0]).is_err());
+    // Leading zero in a numeric pre-release identifier.
+    assert!(Version::from_sortable_bytes(&[0, 0, 0, 1, 1, 1, 2, b'0', b'1', 0]).is_err());
+
+    // Any bytes that decode successfully are the encoding of what they decode
+    // to.
+    for v in VERSION_CORPUS.iter().chain(&["1.0.0-a.1+b.0.07"]) {
+        for bytes in [
+            version(v).to_sortable_bytes(),
+            version(v).to_precedence_bytes(),
+        ] {
+            for i in 0..bytes.len() {
+                let mut mutated = bytes.clone();
+                for byte in 0..=u8::MAX {
+                    mutated[i] = byte;
+                    if let Ok(decoded) = Version::from_sortable_bytes(&mutated) {
+                        assert!(
+                            decoded.to_sortable_bytes() == mutated
+                                || decoded.to_precedence_bytes() == mutated,
+                            "{:?}",
+                            mutated,
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,166 @@
+#![allow(clippy::uninlined_format_args, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{StrictViolation, Version};
+
+// The examples of valid and invalid versions linked from the semver.org FAQ
+// next to the suggested regular expression.
+const SPEC_VALID: &[&str] = &[
+    "0.0.4",
+    "1.2.3",
+    "10.20.30",
+    "1.1.2-prerelease+meta",
+    "1.1.2+meta",
+    "1.1.2+meta-valid",
+    "1.0.0-alpha",
+    "1.0.0-beta",
+    "1.0.0-alpha.beta",
+    "1.0.0-alpha.beta.1",
+    "1.0.0-alpha.1",
+    "1.0.0-alpha0.valid",
+    "1.0.0-alpha.0valid",
+    "1.0.0-alpha-a.b-c-somethinglong+build.1-aef.1-its-okay",
+    "1.0.0-rc.1+build.1",
+    "2.0.0-rc.1+build.123",
+    "1.2.3-beta",
+    "10.2.3-DEV-SNAPSHOT",
+    "1.2.3-SNAPSHOT-123",
+    "1.0.0",
+    "2.0.0",
+    "1.1.7",
+    "2.0.0+build.1848",
+    "2.0.1-alpha.1227",
+    "1.0.0-alpha+beta",
+    "1.2.3----RC-SNAPSHOT.12.9.1--.12+788",
+    "1.2.3----R-S.12.9.1--.12+meta",
+    "1.2.3----RC-SNAPSHOT.12.9.1--.12",
+    "1.0.0+0.build.1-rc.10000aaa-kk-0.1",
+    "99999999999999999999999.999999999999999999.99999999999999999",
+    "1.0.0-0A.is.legal",
+];
+
+const SPEC_INVALID: &[&str] = &[
+    "1",
+    "1.2",
+    "1.2.3-0123",
+    "1.2.3-0123.0123",
+    "1.1.2+.123",
+    "+invalid",
+    "-invalid",
+    "-invalid+invalid",
+    "-invalid.01",
+    "alpha",
+    "alpha.beta",
+    "alpha.beta.1",
+    "alpha.1",
+    "alpha+beta",
+    "alpha_beta",
+    "alpha.",
+    "alpha..",
+    "beta",
+    "1.0.0-alpha_beta",
+    "-alpha.",
+    "1.0.0-alpha..",
+    "1.0.0-alpha..1",
+    "1.0.0-alpha...1",
+    "1.0.0-alpha....1",
+    "1.0.0-alpha.....1",
+    "1.0.0-alpha......1",
+    "1.0.0-alpha.......1",
+    "01.1.1",
+    "1.01.1",
+    "1.1.01",
+    "1.2",
+    "1.2.3.DEV",
+    "1.2-SNAPSHOT",
+    "1.2.31.2.3----RC-SNAPSHOT.12.09.1--..12+788",
+    "1.2-RC-SNAPSHOT",
+    "-1.0.3-gamma+b7718",
+    "+justmeta",
+    "9.8.7+meta+meta",
+    "9.8.7-whatever+meta+meta",
+    "99999999999999999999999.999999999999999999.99999999999999999----RC-SNAPSHOT.12.09.1--------------------------------..12",
+];
+
+#[test]
+fn test_spec_corpus() {
+    for text in SPEC_VALID {
+        match Version::parse_strict(text) {
+            Ok(version) => assert_eq!(version, self::version(text)),
+            Err(error) => {
+                assert!(
+                    error.violations().iter().all(StrictViolation::exceeds_u64),
+                    "{}: {}",
+                    text,
+                    error,
+                );
+                assert!(Version::parse(text).is_err());
+            }
+        }
+    }
+
+    for text in SPEC_INVALID {
+        let error = Version::parse_strict(text).unwrap_err();
+        assert!(
+            error.violations().iter().any(|v| !v.exceeds_u64()),
+            "{}",
+            text,
+        );
+        assert!(Version::parse(text).is_err(), "{}", text);
+    }
+}
+
+#[test]
+fn test_agrees_with_parse() {
+    for text in VERSION_CORPUS
+        .iter()
+        .chain(REQ_CORPUS)
+        .chain(SPEC_VALID)
+        .chain(SPEC_INVALID)
+    {
+        match (Version::parse(text), Version::parse_strict(text)) {
+            (Ok(expected), Ok(actual)) => assert_eq!(expected, actual),
+            (Err(_), Err(_)) => {}
+            (expected, actual) => panic!("{:?}: {:?} vs {:?}", text, expected, actual),
+        }
+    }
+}
+
+#[test]
+fn test_all_violations() {
+    let error = Version::parse_strict("01.2.x-alpha..01+b_1.+").unwrap_err();
+    let spans: Vec<_> = error
+        .violations()
+        .iter()
+        .map(StrictViolation::span)
+        .collect();
+    assert_eq!(spans, [0..2, 5..6, 13..13, 14..16, 18..19, 21..22]);
+    assert_to_string(
+        error,
+        "6 violations of the semver.org grammar: \
+         invalid leading zero in major version number; \
+         unexpected character 'x' in patch version number; \
+         empty identifier segment in pre-release identifier; \
+         invalid leading zero in pre-release identifier; \
+         unexpected character '_' in build metadata; \
+         unexpected character '+' in build metadata",
+    );
+
+    let error = Version::parse_strict("1.2.3.4").unwrap_err();
+    assert_to_string(
+        error,
+        "expected 3 dot-separated numbers in version core, found 4",
+    );
+
+    let error = Version::parse_strict("").unwrap_err();
+    assert_to_string(error, "empty string, expected a semver version");
+
+    let error = Version::parse_strict("18446744073709551616.1.0").unwrap_err();
+    assert!(error.violations()[0].exceeds_u64());
+    assert_to_string(error, "value of major version number exceeds u64::MAX");
+
+    let error = Version::parse_strict("1..3").unwrap_err();
+    assert_to_string(error, "empty minor version number");
+}
@@ -0,0 +1,147 @@
+#![allow(clippy::uninlined_format_args, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::TagScheme;
+
+fn split(scheme: &TagScheme, text: &str) -> Vec<String> {
+    scheme
+        .split(text)
+        .into_iter()
+        .map(|(name, version)| format!("{} {}", name, version))
+        .collect()
+}
+
+#[test]
+fn test_predefined() {
+    let crate_file = TagScheme::CRATE_FILE;
+    assert_eq!(
+        split(&crate_file, "serde_json-1.0.108.crate"),
+        ["serde_json 1.0.108"],
+    );
+    assert_eq!(
+        split(&crate_file, "my-crate-0.1.0-alpha.1.crate"),
+        ["my-crate 0.1.0-alpha.1"],
+    );
+    assert_eq!(
+        split(&crate_file, "foo-1.0.0-rc.1+build-5.crate"),
+        ["foo 1.0.0-rc.1+build-5"],
+    );
+    assert!(split(&crate_file, "serde_json-1.0.108").is_empty());
+    assert!(split(&crate_file, "serde_json-1.0.crate").is_empty());
+
+    assert_eq!(
+        split(&TagScheme::NAME_VERSION, "my-crate-0.1.0-alpha.1"),
+        ["my-crate 0.1.0-alpha.1"],
+    );
+    assert_eq!(
+        split(&TagScheme::NAME_VERSION, "x86-64-2.0.0"),
+        ["x86-64 2.0.0"],
+    );
+    assert_eq!(
+        split(&TagScheme::NAME_V_VERSION, "foo-v2.3.4"),
+        ["foo 2.3.4"],
+    );
+    assert_eq!(
+        split(&TagScheme::NAME_V_VERSION, "foo-v1-v2.3.4"),
+        ["foo-v1 2.3.4"],
+    );
+    assert_eq!(split(&TagScheme::V_VERSION, "v2.3.4-rc.1"), [" 2.3.4-rc.1"]);
+    assert!(split(&TagScheme::V_VERSION, "foo-v2.3.4").is_empty());
+    assert_eq!(split(&TagScheme::RELEASE, "release/2.3.4"), [" 2.3.4"]);
+    assert!(split(&TagScheme::RELEASE, "release/v2.3.4").is_empty());
+}
+
+#[test]
+fn test_invalid_names() {
+    // Crate names start with a letter and contain only letters, digits, `-`
+    // and `_`.
+    let scheme = TagScheme::NAME_VERSION;
+    assert!(split(&scheme, "1foo-1.0.0").is_empty());
+    assert!(split(&scheme, "-1.0.0").is_empty());
+    assert!(split(&scheme, "foo.bar-1.0.0").is_empty());
+    assert!(split(&scheme, "foo/bar-1.0.0").is_empty());
+}
+
+#[test]
+fn test_ambiguous() {
+    let scheme = TagScheme::new("{name}{version}").unwrap();
+    assert_eq!(split(&scheme, "abc21.0.0"), ["abc 21.0.0", "abc2 1.0.0"]);
+
+    let scheme = TagScheme::new("{version}-{name}").unwrap();
+    assert_eq!(
+        split(&scheme, "1.0.0-rc-linux"),
+        ["linux 1.0.0-rc", "rc-linux 1.0.0"],
+    );
+}
+
+#[test]
+fn test_format() {
+    let version = version("1.2.3-alpha.1+build.5");
+    assert_eq!(
+        TagScheme::CRATE_FILE.format("my-crate", &version),
+        "my-crate-1.2.3-alpha.1+build.5.crate",
+    );
+    assert_eq!(
+        TagScheme::NAME_V_VERSION.format("foo", &version),
+        "foo-v1.2.3-alpha.1+build.5",
+    );
+    assert_eq!(
+        TagScheme::RELEASE.format("foo", &version),
+        "release/1.2.3-alpha.1+build.5",
+    );
+
+    let scheme = TagScheme::new("{version}/{name}.tar.gz").unwrap();
+    let tag = scheme.format("foo_bar", &version);
+    assert_eq!(tag, "1.2.3-alpha.1+build.5/foo_bar.tar.gz");
+    let (name, parsed) = &scheme.split(&tag)[0];
+    assert_eq!(*name, "foo_bar");
+    assert_eq!(*parsed, version);
+}
+
+#[test]
+fn test_roundtrip() {
+    let schemes = [
+        TagScheme::CRATE_FILE,
+        TagScheme::NAME_VERSION,
+        TagScheme::NAME_V_VERSION,
+        TagScheme::V_VERSION,
+        TagScheme::RELEASE,
+    ];
+    for scheme in &schemes {
+        let has_name = scheme.template().contains("{name}");
+        for text in VERSION_CORPUS {
+            let version = version(text);
+            for name in ["a", "my-crate", "x86_64-v1"] {
+                let tag = scheme.format(name, &version);
+                let expected = (if has_name { name } else { "" }, version.clone());
+                assert_eq!(scheme.split(&tag), [expected], "{}", tag);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_invalid_template() {
+    let err = TagScheme::new("v{Version}").unwrap_err();
+    assert_to_string(
+        err,
+        "unknown placeholder or unmatched brace at byte 1 of template",
+    );
+
+    let err = TagScheme::new("{name}-{version}}").unwrap_err();
+    assert_to_string(
+        err,
+        "unknown placeholder or unmatched brace at byte 16 of template",
+    );
+
+    let err = TagScheme::new("{name}").unwrap_err();
+    assert_to_string(err, "tag template must contain {version} exactly once");
+
+    let err = TagScheme::new("{version}-{version}").unwrap_err();
+    assert_to_string(err, "tag template must contain {version} exactly once");
+
+    let err = TagScheme::new("{name}-{name}-{version}").unwrap_err();
+    assert_to_string(err, "tag template must not contain {name} more than once");
+}
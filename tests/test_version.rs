@@ -242,6 +242,47 @@ fn test_spec_order() {
     }
 }
 
+#[test]
+fn test_cmp_natural() {
+    let vs = [
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-alpha0",
+        "1.0.0-alpha01",
+        "1.0.0-alpha1",
+        "1.0.0-alpha9",
+        "1.0.0-alpha10",
+        "1.0.0-alpha10b",
+        "1.0.0-alpha10b2",
+        "1.0.0-alpha10b10",
+        "1.0.0-alpha10c",
+        "1.0.0-alpha-2",
+        "1.0.0-beta.2",
+        "1.0.0-beta.11",
+        "1.0.0-rc.1",
+        "1.0.0",
+        "1.0.0+build10",
+        "1.0.0+build9",
+        "1.0.1-2",
+        "1.0.1-a",
+    ];
+    for (i, a) in vs.iter().enumerate() {
+        for (j, b) in vs.iter().enumerate() {
+            let ordering = version(a).cmp_natural(&version(b));
+            assert_eq!(ordering, i.cmp(&j), "{} vs {}", a, b);
+        }
+    }
+
+    // Agrees with Ord wherever no alphanumeric identifier contains digits.
+    for a in VERSION_CORPUS {
+        for b in VERSION_CORPUS {
+            let (a, b) = (version(a), version(b));
+            assert_eq!(a.cmp_natural(&b), a.cmp(&b), "{} vs {}", a, b);
+        }
+    }
+}
+
 #[test]
 fn test_align() {
     let version = version("1.2.3-rc1");
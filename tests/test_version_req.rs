@@ -16,7 +16,7 @@ use std::hash::{Hash, Hasher};
 #[cfg(test_node_semver)]
 use node::{req, VersionReq};
 #[cfg(not(test_node_semver))]
-use semver::VersionReq;
+use semver::{RangePosition, VersionReq};
 
 #[track_caller]
 fn assert_match_all(req: &VersionReq, versions: &[&str]) {
@@ -478,3 +478,45 @@ fn test_wildcard_and_another() {
         "wildcard req (*) must be the only comparator in the version req",
     );
 }
+
+#[test]
+#[cfg(not(test_node_semver))]
+fn test_position() {
+    for r in REQ_CORPUS {
+        let ref r = req(r);
+        for v in VERSION_CORPUS {
+            let ref v = version(v);
+            let within = r.position(v) == RangePosition::Within;
+            assert_eq!(within, r.matches(v), "{} {}", r, v);
+        }
+    }
+
+    let ref r = req("^1.2.3");
+    assert_eq!(r.position(&version("1.2.2")), RangePosition::Below);
+    assert_eq!(r.position(&version("1.2.3-rc.1")), RangePosition::Below);
+    assert_eq!(r.position(&version("1.9.0")), RangePosition::Within);
+    assert_eq!(
+        r.position(&version("1.9.0-rc.1")),
+        RangePosition::PrereleaseExcluded,
+    );
+    assert_eq!(r.position(&version("2.0.0-rc.1")), RangePosition::Above);
+    assert_eq!(r.position(&version("2.0.0")), RangePosition::Above);
+
+    let ref r = req(">=1.2, <1.2.7-rc.1");
+    assert_eq!(
+        r.position(&version("1.2.7-alpha")),
+        RangePosition::PrereleaseExcluded,
+    );
+
+    let ref r = req("<=0.2.0, >=0.5.0");
+    assert_eq!(r.position(&version("0.1.0")), RangePosition::Below);
+    assert_eq!(r.position(&version("0.3.0")), RangePosition::Gap);
+    assert_eq!(r.position(&version("0.6.0")), RangePosition::Above);
+
+    let ref r = VersionReq::STAR;
+    assert_eq!(r.position(&version("0.0.0")), RangePosition::Within);
+    assert_eq!(
+        r.position(&version("1.0.0-alpha")),
+        RangePosition::PrereleaseExcluded,
+    );
+}
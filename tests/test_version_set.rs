@@ -0,0 +1,130 @@
+#![allow(clippy::uninlined_format_args, clippy::wildcard_imports)]
+
+mod util;
+
+use crate::util::*;
+use semver::{Version, VersionSet};
+
+fn set(versions: &[&str]) -> VersionSet {
+    versions.iter().map(|text| version(text)).collect()
+}
+
+fn strings<'a>(versions: impl IntoIterator<Item = &'a Version>) -> Vec<String> {
+    versions.into_iter().map(Version::to_string).collect()
+}
+
+const PUBLISHED: &[&str] = &[
+    "0.0.1",
+    "0.0.2",
+    "0.1.0",
+    "0.1.5",
+    "0.2.0-alpha",
+    "1.0.0",
+    "1.2.0",
+    "1.2.3",
+    "1.2.3+build.5",
+    "1.2.4-rc.1",
+    "1.9.0",
+    "2.0.0-alpha.1",
+    "2.0.0-beta.1",
+    "2.0.0",
+    "2.1.0",
+    "3.0.0-rc.1",
+];
+
+#[test]
+fn test_insert_remove() {
+    let mut set = VersionSet::new();
+    assert!(set.is_empty());
+    assert!(set.insert(version("1.0.0")));
+    assert!(set.insert(version("0.9.0")));
+    assert!(!set.insert(version("1.0.0")));
+    assert!(set.insert(version("1.0.0+build")));
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&version("1.0.0+build")));
+    assert_eq!(strings(&set), ["0.9.0", "1.0.0", "1.0.0+build"]);
+
+    assert!(set.remove(&version("1.0.0")));
+    assert!(!set.remove(&version("1.0.0")));
+    assert_eq!(strings(set.iter()), ["0.9.0", "1.0.0+build"]);
+
+    set.extend([version("2.0.0"), version("0.9.0")]);
+    let owned: Vec<Version> = set.into_iter().collect();
+    assert_eq!(strings(&owned), ["0.9.0", "1.0.0+build", "2.0.0"]);
+}
+
+#[test]
+fn test_matching() {
+    let set = set(PUBLISHED);
+
+    let matching = |text: &str| strings(set.matching(&req(text)));
+    assert_eq!(
+        matching("^1.2"),
+        ["1.2.0", "1.2.3", "1.2.3+build.5", "1.9.0"]
+    );
+    assert_eq!(matching(">1.2.3, <2"), ["1.9.0"]);
+    assert_eq!(matching(">=1.2.4-rc.1, <1.3"), ["1.2.4-rc.1"]);
+    assert_eq!(matching("=1.2.3"), ["1.2.3", "1.2.3+build.5"]);
+    assert_eq!(matching("^0.1"), ["0.1.0", "0.1.5"]);
+    assert_eq!(
+        matching(">=2.0.0-alpha.1"),
+        ["2.0.0-alpha.1", "2.0.0-beta.1", "2.0.0", "2.1.0"]
+    );
+    assert_eq!(matching("*").len(), 11);
+    assert!(matching("^4").is_empty());
+
+    for text in REQ_CORPUS {
+        let req = req(text);
+        let expected: Vec<String> = strings(set.iter().filter(|v| req.matches(v)));
+        assert_eq!(strings(set.matching(&req)), expected, "{}", text);
+    }
+}
+
+#[test]
+fn test_latest() {
+    let set = set(PUBLISHED);
+    assert_eq!(set.latest(false).unwrap().to_string(), "2.1.0");
+    assert_eq!(set.latest(true).unwrap().to_string(), "3.0.0-rc.1");
+
+    let set = self::set(&["1.0.0-alpha", "1.0.0-beta"]);
+    assert_eq!(set.latest(false), None);
+    assert_eq!(set.latest(true).unwrap().to_string(), "1.0.0-beta");
+
+    assert_eq!(VersionSet::new().latest(true), None);
+}
+
+#[test]
+fn test_latest_per_compat_bucket() {
+    let set = set(PUBLISHED);
+    assert_eq!(
+        strings(set.latest_per_compat_bucket()),
+        [
+            "0.0.1",
+            "0.0.2",
+            "0.1.5",
+            "0.2.0-alpha",
+            "1.9.0",
+            "2.1.0",
+            "3.0.0-rc.1",
+        ],
+    );
+
+    assert!(VersionSet::new().latest_per_compat_bucket().is_empty());
+}
+
+#[test]
+fn test_neighbors() {
+    let set = set(PUBLISHED);
+    let predecessor = |text: &str| set.predecessor(&version(text)).map(Version::to_string);
+    let successor = |text: &str| set.successor(&version(text)).map(Version::to_string);
+
+    assert_eq!(predecessor("1.2.3").unwrap(), "1.2.0");
+    assert_eq!(successor("1.2.3").unwrap(), "1.2.3+build.5");
+    assert_eq!(successor("1.2.3+build.5").unwrap(), "1.2.4-rc.1");
+    assert_eq!(predecessor("2.0.0").unwrap(), "2.0.0-beta.1");
+    assert_eq!(successor("1.5.0").unwrap(), "1.9.0");
+    assert_eq!(predecessor("1.5.0").unwrap(), "1.2.4-rc.1");
+    assert_eq!(predecessor("0.0.1"), None);
+    assert_eq!(successor("3.0.0-rc.1"), None);
+    assert_eq!(successor("0.0.0").unwrap(), "0.0.1");
+}
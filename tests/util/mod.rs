@@ -3,6 +3,96 @@
 use semver::{BuildMetadata, Comparator, Error, Prerelease, Version, VersionReq};
 use std::fmt::Display;
 
+// Requirements and versions around the edges of each kind of comparator, for
+// checking that alternative evaluation strategies agree with VersionReq::matches.
+pub(super) const REQ_CORPUS: &[&str] = &[
+    "*",
+    "1",
+    "1.2",
+    "1.2.3",
+    "1.2.3-beta.2",
+    "0",
+    "0.0",
+    "0.2",
+    "0.0.3",
+    "0.2.3-alpha",
+    "0.0.3-a",
+    "=1",
+    "=1.2",
+    "=1.2.3",
+    "=1.2.3-beta.2",
+    ">1",
+    ">1.2",
+    ">1.2.3",
+    ">1.2.3-beta.2",
+    ">=1",
+    ">=1.2",
+    ">=1.2.3",
+    ">=1.2.3-beta.2",
+    "<1",
+    "<1.2",
+    "<1.2.3",
+    "<1.2.3-beta.2",
+    "<=1",
+    "<=1.2",
+    "<=1.2.3",
+    "<=1.2.3-beta.2",
+    "~1",
+    "~1.2",
+    "~1.2.3",
+    "~1.2.3-beta.2",
+    "1.*",
+    "1.2.*",
+    ">=1.2, <1.2.7-rc.1",
+    ">=1.2.3-alpha, <2",
+    "<=1.2, >=1.2.3-alpha",
+    ">18446744073709551615",
+    ">1.18446744073709551615",
+    "^18446744073709551615.1.2",
+    "^0.0.18446744073709551615",
+];
+
+pub(super) const VERSION_CORPUS: &[&str] = &[
+    "0.0.0",
+    "0.0.3-a",
+    "0.0.3",
+    "0.0.4-0",
+    "0.0.4",
+    "0.2.2",
+    "0.2.3-alpha",
+    "0.2.3",
+    "0.2.9",
+    "0.3.0-0",
+    "0.3.0",
+    "0.9.0",
+    "1.0.0-0",
+    "1.0.0",
+    "1.1.9",
+    "1.2.0-rc.1",
+    "1.2.0",
+    "1.2.2",
+    "1.2.3-alpha",
+    "1.2.3-beta.1",
+    "1.2.3-beta.2",
+    "1.2.3-beta.11",
+    "1.2.3",
+    "1.2.4-0",
+    "1.2.4",
+    "1.2.7-rc.1",
+    "1.3.0-0",
+    "1.3.0",
+    "1.9.9",
+    "2.0.0-0",
+    "2.0.0",
+    "3.1.4",
+    "1.18446744073709551615.0",
+    "18446744073709551615.0.0",
+    "18446744073709551615.1.2",
+    "18446744073709551615.1.3-x",
+    "0.0.18446744073709551615",
+    "0.1.0-0",
+];
+
 #[track_caller]
 pub(super) fn version(text: &str) -> Version {
     Version::parse(text).unwrap()